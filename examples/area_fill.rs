@@ -0,0 +1,407 @@
+//! Area Fill Example
+//!
+//! This example demonstrates how to fill the region under a curve with an
+//! `Area`. An area is bounded above by a series and below either by a constant
+//! baseline or by a second series, as built with `Area::fill_between`. Its
+//! outline is plotted in the axes, and its inside is a translucent polygon
+//! painted over the figure with the shared `fill_layer`.
+//!
+//! The first mode shows a confidence band of two standard deviations around
+//! a mean, over the min/max envelope of the raw samples. The second mode
+//! stacks three series on top of each other with `stack`. Areas map their
+//! values through the shared y `Scale` before filling, so both modes can be
+//! shown on a linear or a logarithmic y axis.
+//!
+//! The areas are plotted once. Changing the mode or the scale plots the new
+//! areas into the same `AxesModel` and writes its bounds.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{fill_layer, Polygon, Scale};
+use gpui::{
+    div, prelude::*, px, size, App, Application, Bounds, ClickEvent, Entity, Hsla, Window,
+    WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::{AxesContext, AxesModel};
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, GeometryAxes, Line};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+const X_MIN: f64 = 0.0;
+const X_MAX: f64 = 10.0;
+
+/// Smallest value shown on a logarithmic axis
+const LOG_FLOOR: f64 = 0.1;
+
+/// Main application view containing the filled areas
+struct AreaFillView {
+    axes_model: Arc<RwLock<AxesModel<f64, f64>>>,
+    figure: Entity<FigureView>,
+    stacked: bool,
+    scale: Scale,
+    /// Areas of the current mode and scale, as plotted
+    areas: Vec<Area>,
+}
+
+impl AreaFillView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Area Fill - bands and stacked areas".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        let areas = Self::areas(false, Scale::Linear);
+        let grid = GridModel::from_numbers(10, 8);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(Self::bounds(&areas), grid)));
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |_axes| {});
+        });
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        let view = Self {
+            axes_model,
+            figure,
+            stacked: false,
+            scale: Scale::Linear,
+            areas,
+        };
+        view.plot();
+        view
+    }
+
+    /// Plots the areas, and the mean in band mode, into the axes model,
+    /// replacing its elements and fitting its bounds
+    fn plot(&self) {
+        let mut axes = self.axes_model.write();
+        axes.bounds = Self::bounds(&self.areas);
+        axes.clear_elements();
+        for area in &self.areas {
+            axes.plot(area.clone());
+        }
+        if !self.stacked {
+            let mut line = Line::new().color(Hsla::blue());
+            for i in 0..=200 {
+                let x = X_MIN + (X_MAX - X_MIN) * i as f64 / 200.0;
+                line.add_point(point2(x, forward(self.scale, mean(x))));
+            }
+            axes.plot(line);
+        }
+    }
+
+    /// Areas of the current mode, in the order they are drawn
+    fn areas(stacked: bool, scale: Scale) -> Vec<Area> {
+        let xs = (0..=200).map(|i| X_MIN + (X_MAX - X_MIN) * i as f64 / 200.0);
+        if stacked {
+            let layers = [
+                (Hsla::blue(), 0.0),
+                (Hsla::green(), 2.0),
+                (Hsla::red(), 4.0),
+            ]
+            .map(|(color, phase)| {
+                let points = xs
+                    .clone()
+                    .map(|x| (x, 1.5 + (x * 0.8 + phase).sin()))
+                    .collect();
+                (points, color)
+            });
+            return stack(&layers)
+                .into_iter()
+                .map(|area| area.scale(scale))
+                .collect();
+        }
+
+        // Samples scattered around the mean with a growing spread
+        let samples = |x: f64| {
+            (0..20).map(move |i| {
+                let noise = ((x * 97.0 + i as f64) * 12.9898).sin() * 43758.5453;
+                mean(x) + 2.0 * sigma(x) * noise.fract()
+            })
+        };
+        let envelope = |pick: fn(f64, f64) -> f64, start: f64| -> Points {
+            xs.clone()
+                .map(|x| (x, samples(x).fold(start, pick)))
+                .collect()
+        };
+        let band = |k: f64| -> Points { xs.clone().map(|x| (x, mean(x) + k * sigma(x))).collect() };
+
+        vec![
+            Area::fill_between(
+                envelope(f64::max, f64::MIN),
+                envelope(f64::min, f64::MAX),
+                Hsla::black(),
+            )
+            .alpha(0.1)
+            .scale(scale),
+            Area::fill_between(band(2.0), band(-2.0), Hsla::blue())
+                .alpha(0.3)
+                .outline(false)
+                .scale(scale),
+        ]
+    }
+
+    /// Axes bounds fitting the areas, in scaled units for y
+    fn bounds(areas: &[Area]) -> AxesBounds<f64, f64> {
+        let (min, max) = areas
+            .iter()
+            .map(Area::y_extent)
+            .fold((f64::MAX, f64::MIN), |(min, max), (low, high)| {
+                (min.min(low), max.max(high))
+            });
+        let padding = (max - min).max(f64::EPSILON) * 0.05;
+        AxesBounds::new(
+            AxisRange::new(X_MIN, X_MAX),
+            AxisRange::new(min - padding, max + padding),
+        )
+    }
+
+    fn render_button(
+        &self,
+        id: &'static str,
+        label: String,
+        cx: &mut Context<Self>,
+        action: fn(&mut Self),
+    ) -> impl IntoElement {
+        div()
+            .id(id)
+            .px_2()
+            .border_1()
+            .border_color(Hsla::black())
+            .cursor_pointer()
+            .on_click(cx.listener(move |this, _: &ClickEvent, _window, cx| {
+                action(this);
+                this.areas = Self::areas(this.stacked, this.scale);
+                this.plot();
+                cx.notify();
+            }))
+            .child(label)
+    }
+}
+
+impl Render for AreaFillView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let fills = self.areas.iter().map(Area::fill).collect();
+        let bounds = self.axes_model.read().bounds;
+        let (x, y) = ((bounds.x.min, bounds.x.max), (bounds.y.min, bounds.y.max));
+
+        let mode = if self.stacked { "Stacked" } else { "Band" };
+        let scale = match self.scale {
+            Scale::Linear => "Linear",
+            _ => "Log10",
+        };
+        let buttons = [
+            self.render_button("mode", format!("Mode: {mode}"), cx, |this| {
+                this.stacked = !this.stacked
+            }),
+            self.render_button("scale", format!("Y scale: {scale}"), cx, |this| {
+                this.scale = match this.scale {
+                    Scale::Linear => Scale::Log { base: 10.0 },
+                    _ => Scale::Linear,
+                }
+            }),
+        ];
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(div().flex().flex_row().gap_2().p_2().children(buttons))
+            .child(
+                div()
+                    .relative()
+                    .size_full()
+                    .child(self.figure.clone())
+                    .child(fill_layer(fills, x, y)),
+            )
+    }
+}
+
+/// Points of a series, sorted by x
+type Points = Vec<(f64, f64)>;
+
+/// Maps a y value through `scale`, clamping it to `LOG_FLOOR` on a log scale
+fn forward(scale: Scale, value: f64) -> f64 {
+    match scale {
+        Scale::Log { .. } => scale.forward(value.max(LOG_FLOOR)),
+        _ => scale.forward(value),
+    }
+}
+
+/// Lower boundary of an `Area`
+#[derive(Clone, Debug)]
+enum Lower {
+    Baseline(f64),
+    Series(Points),
+}
+
+/// Region between a series and a baseline or a second series
+#[derive(Clone)]
+struct Area {
+    upper: Points,
+    lower: Lower,
+    color: Hsla,
+    alpha: f32,
+    outline: bool,
+    scale: Scale,
+}
+
+impl Area {
+    /// Area between `upper` and a baseline at 0
+    fn new(upper: Points, color: Hsla) -> Self {
+        Self {
+            upper,
+            lower: Lower::Baseline(0.0),
+            color,
+            alpha: 0.4,
+            outline: true,
+            scale: Scale::Linear,
+        }
+    }
+
+    /// Area between two series, which do not need to share their x values
+    fn fill_between(upper: Points, lower: Points, color: Hsla) -> Self {
+        Self {
+            lower: Lower::Series(lower),
+            ..Self::new(upper, color)
+        }
+    }
+
+    fn baseline(mut self, baseline: f64) -> Self {
+        self.lower = Lower::Baseline(baseline);
+        self
+    }
+
+    fn alpha(mut self, alpha: f32) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    fn outline(mut self, outline: bool) -> Self {
+        self.outline = outline;
+        self
+    }
+
+    fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Both boundaries in scaled units
+    fn scaled(&self) -> (Points, Points) {
+        let scale = |points: &[(f64, f64)]| {
+            points
+                .iter()
+                .map(|&(x, y)| (x, forward(self.scale, y)))
+                .collect::<Vec<_>>()
+        };
+        let upper = scale(&self.upper);
+        let lower = match &self.lower {
+            Lower::Baseline(y) => self
+                .upper
+                .iter()
+                .map(|&(x, _)| (x, forward(self.scale, *y)))
+                .collect(),
+            Lower::Series(points) => scale(points),
+        };
+        (upper, lower)
+    }
+
+    /// Region between both boundaries, in scaled units so that it follows
+    /// the drawn curves
+    fn fill(&self) -> Polygon {
+        let (upper, lower) = self.scaled();
+        let points = upper.into_iter().chain(lower.into_iter().rev()).collect();
+        let color = Hsla {
+            a: self.alpha,
+            ..self.color
+        };
+        Polygon::new(points, color)
+    }
+
+    fn y_extent(&self) -> (f64, f64) {
+        let (upper, lower) = self.scaled();
+        upper
+            .iter()
+            .chain(&lower)
+            .fold((f64::MAX, f64::MIN), |(min, max), &(_, y)| {
+                (min.min(y), max.max(y))
+            })
+    }
+}
+
+impl GeometryAxes for Area {
+    type X = f64;
+    type Y = f64;
+
+    /// Draws the outline only, the inside is painted by `fill_layer`
+    fn render_axes(&mut self, cx: &mut AxesContext<Self::X, Self::Y>) {
+        let (upper, lower) = self.scaled();
+        if self.outline {
+            let boundaries = match self.lower {
+                Lower::Baseline(_) => vec![upper],
+                Lower::Series(_) => vec![upper, lower],
+            };
+            for boundary in boundaries {
+                let mut line = Line::new().color(self.color);
+                for (x, y) in boundary {
+                    line.add_point(point2(x, y));
+                }
+                line.render_axes(cx);
+            }
+        }
+    }
+}
+
+/// Mean of the samples of the band mode
+fn mean(x: f64) -> f64 {
+    6.0 + 3.0 * (x * 0.7).sin()
+}
+
+/// Standard deviation of the samples of the band mode
+fn sigma(x: f64) -> f64 {
+    0.2 + 0.08 * x
+}
+
+/// Stacks series sharing their x values, each area starting on the previous
+fn stack(layers: &[(Points, Hsla)]) -> Vec<Area> {
+    let mut areas: Vec<Area> = Vec::new();
+    for (points, color) in layers {
+        let area = match areas.last() {
+            None => Area::new(points.clone(), *color).baseline(0.0),
+            Some(below) => {
+                let upper = points
+                    .iter()
+                    .zip(&below.upper)
+                    .map(|(&(x, y), &(_, base))| (x, base + y))
+                    .collect();
+                Area::fill_between(upper, below.upper.clone(), *color)
+            }
+        };
+        areas.push(area);
+    }
+    areas
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our filled areas
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| AreaFillView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
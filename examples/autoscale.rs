@@ -0,0 +1,345 @@
+//! Autoscale Example
+//!
+//! This example demonstrates how to compute the axes bounds from the plotted
+//! data instead of hand-computing them. Each `Series` reports its data
+//! `Extent`, the extents of all series are merged, and `Autoscale` turns the
+//! result into `AxesBounds` with optional padding, "nice" rounding of the
+//! limits to tick values, per-axis locks and a tight mode.
+//!
+//! The data changes over time: a task regenerates it `REFRESH_RATE` times per
+//! second, and only then are the bounds recomputed and the curves plotted
+//! again. The buttons on top toggle the tight mode, the rounding of the limits
+//! and the locks of each axis, which only recomputes the bounds.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::nice_step;
+use gpui::{
+    div, prelude::*, px, size, App, Application, Bounds, ClickEvent, Entity, Hsla, Task, Window,
+    WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::{AxesContext, AxesModel};
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, GeometryAxes, Line};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Number of times per second the data is regenerated
+const REFRESH_RATE: f64 = 20.0;
+
+/// Main application view containing the autoscaled plot
+struct AutoscaleView {
+    axes_model: Arc<RwLock<AxesModel<f64, f64>>>,
+    figure: Entity<FigureView>,
+    autoscale: Autoscale,
+    series: Vec<Series>,
+    /// Regenerates the data until the view is dropped
+    _refresh: Task<()>,
+}
+
+impl AutoscaleView {
+    fn new(_window: &mut Window, cx: &mut Context<Self>) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Autoscale - bounds follow the data".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        // Initial bounds, replaced as soon as data is available
+        let x_range = AxisRange::new(0.0, 1.0);
+        let y_range = AxisRange::new(0.0, 1.0);
+        let axes_bounds = AxesBounds::new(x_range, y_range);
+
+        let grid = GridModel::from_numbers(10, 8);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(axes_bounds, grid)));
+
+        // The axes are added once, only their elements and bounds change
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |_| {});
+        });
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        let start = Instant::now();
+        let refresh = cx.spawn(async move |this, cx| loop {
+            let interval = Duration::from_secs_f64(1.0 / REFRESH_RATE);
+            cx.background_executor().timer(interval).await;
+            let updated = this.update(cx, |this, cx| {
+                this.set_series(Self::series(start.elapsed().as_secs_f64()));
+                cx.notify();
+            });
+            // The view is gone
+            if updated.is_err() {
+                break;
+            }
+        });
+
+        let mut view = Self {
+            axes_model,
+            figure,
+            autoscale: Autoscale::new().padding(0.05),
+            series: Vec::new(),
+            _refresh: refresh,
+        };
+        view.set_series(Self::series(0.0));
+        view
+    }
+
+    /// Two curves whose length and amplitude change with the time `t`
+    fn series(t: f64) -> Vec<Series> {
+        let length = 2.0 + (t % 10.0);
+        let amplitude = 1.0 + 4.0 * (0.3 * t).sin().abs();
+
+        let damped = Series::sample(Hsla::blue(), length, |x| {
+            amplitude * (-0.2 * x).exp() * (3.0 * x).sin()
+        });
+        let drift = Series::sample(Hsla::red(), length, |x| {
+            0.5 * amplitude * (0.5 * x).cos() - 1.0
+        });
+        vec![damped, drift]
+    }
+
+    /// Replaces the plotted data and rescales the axes to it
+    fn set_series(&mut self, series: Vec<Series>) {
+        self.series = series;
+        let mut axes = self.axes_model.write();
+        axes.clear_elements();
+        for series in &self.series {
+            axes.plot(series.clone());
+        }
+        drop(axes);
+        self.update_bounds();
+    }
+
+    /// Recomputes the bounds from the extent of all series
+    fn update_bounds(&mut self) {
+        let extent = self
+            .series
+            .iter()
+            .filter_map(Series::extent)
+            .reduce(Extent::union);
+        if let Some(extent) = extent {
+            self.axes_model.write().bounds = self.autoscale.bounds(extent);
+        }
+    }
+
+    fn render_toggle(
+        &self,
+        id: &'static str,
+        label: &str,
+        enabled: bool,
+        cx: &mut Context<Self>,
+        toggle: fn(&mut Autoscale),
+    ) -> impl IntoElement {
+        let state = if enabled { "on" } else { "off" };
+        div()
+            .id(id)
+            .px_2()
+            .border_1()
+            .border_color(Hsla::black())
+            .cursor_pointer()
+            .on_click(cx.listener(move |this, _: &ClickEvent, _window, cx| {
+                toggle(&mut this.autoscale);
+                this.update_bounds();
+                cx.notify();
+            }))
+            .child(format!("{label}: {state}"))
+    }
+}
+
+impl Render for AutoscaleView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let tight = self.render_toggle("tight", "Tight", self.autoscale.tight, cx, |a| {
+            a.tight = !a.tight;
+        });
+        let nice = self.render_toggle("nice", "Nice limits", self.autoscale.nice, cx, |a| {
+            a.nice = !a.nice;
+        });
+        let lock_x = self.render_toggle(
+            "lock-x",
+            "Lock x to [0, 12]",
+            self.autoscale.x_lock.is_some(),
+            cx,
+            |a| {
+                a.x_lock = match a.x_lock {
+                    Some(_) => None,
+                    None => Some((0.0, 12.0)),
+                };
+            },
+        );
+        let lock_y = self.render_toggle(
+            "lock-y",
+            "Lock y to [-6, 6]",
+            self.autoscale.y_lock.is_some(),
+            cx,
+            |a| {
+                a.y_lock = match a.y_lock {
+                    Some(_) => None,
+                    None => Some((-6.0, 6.0)),
+                };
+            },
+        );
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .gap_2()
+                    .p_2()
+                    .child(tight)
+                    .child(nice)
+                    .child(lock_x)
+                    .child(lock_y),
+            )
+            .child(self.figure.clone())
+    }
+}
+
+/// Range covered by data along both axes
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Extent {
+    x: (f64, f64),
+    y: (f64, f64),
+}
+
+impl Extent {
+    /// Smallest extent containing both `self` and `other`
+    fn union(self, other: Extent) -> Extent {
+        Extent {
+            x: (self.x.0.min(other.x.0), self.x.1.max(other.x.1)),
+            y: (self.y.0.min(other.y.0), self.y.1.max(other.y.1)),
+        }
+    }
+}
+
+/// A curve that can report the extent of its data
+#[derive(Clone)]
+struct Series {
+    color: Hsla,
+    points: Vec<(f64, f64)>,
+}
+
+impl Series {
+    /// Samples `f` from 0 to `length`
+    fn sample(color: Hsla, length: f64, f: impl Fn(f64) -> f64) -> Self {
+        let step = 0.02;
+        let count = (length / step) as usize;
+        let points = (0..=count)
+            .map(|i| {
+                let x = i as f64 * step;
+                (x, f(x))
+            })
+            .collect();
+        Self { color, points }
+    }
+
+    /// Extent of the finite points, or `None` if there are none
+    fn extent(&self) -> Option<Extent> {
+        self.points
+            .iter()
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .map(|&(x, y)| Extent {
+                x: (x, x),
+                y: (y, y),
+            })
+            .reduce(Extent::union)
+    }
+}
+
+impl GeometryAxes for Series {
+    type X = f64;
+    type Y = f64;
+
+    fn render_axes(&mut self, cx: &mut AxesContext<Self::X, Self::Y>) {
+        let mut line = Line::new().color(self.color);
+        for &(x, y) in &self.points {
+            line.add_point(point2(x, y));
+        }
+        line.render_axes(cx);
+    }
+}
+
+/// Rules turning a data extent into axes bounds
+#[derive(Clone, Debug)]
+struct Autoscale {
+    /// Margin added on each side, as a fraction of the data span
+    padding: f64,
+    /// Round the limits outwards to the nearest tick value
+    nice: bool,
+    /// Use the data extent as is, without padding nor rounding
+    tight: bool,
+    x_lock: Option<(f64, f64)>,
+    y_lock: Option<(f64, f64)>,
+}
+
+impl Autoscale {
+    fn new() -> Self {
+        Self {
+            padding: 0.0,
+            nice: true,
+            tight: false,
+            x_lock: None,
+            y_lock: None,
+        }
+    }
+
+    fn padding(mut self, padding: f64) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    fn bounds(&self, extent: Extent) -> AxesBounds<f64, f64> {
+        let (x_min, x_max) = self.x_lock.unwrap_or_else(|| self.axis(extent.x));
+        let (y_min, y_max) = self.y_lock.unwrap_or_else(|| self.axis(extent.y));
+        AxesBounds::new(AxisRange::new(x_min, x_max), AxisRange::new(y_min, y_max))
+    }
+
+    fn axis(&self, (mut min, mut max): (f64, f64)) -> (f64, f64) {
+        // A single value still gets a non-empty range around it
+        if min == max {
+            let half = if min == 0.0 { 0.5 } else { min.abs() * 0.05 };
+            min -= half;
+            max += half;
+        }
+        if self.tight {
+            return (min, max);
+        }
+
+        let padding = (max - min) * self.padding;
+        min -= padding;
+        max += padding;
+        if self.nice {
+            let step = nice_step(min, max, 8);
+            min = (min / step).floor() * step;
+            max = (max / step).ceil() * step;
+        }
+        (min, max)
+    }
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our autoscaled plot
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| AutoscaleView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
@@ -0,0 +1,647 @@
+//! Axis Decoration Example
+//!
+//! This example demonstrates how to decorate axes with titles, major and minor
+//! tick marks and formatted tick labels. Tick positions are generated from the
+//! axis range with "nice" steps (1, 2 or 5 times a power of ten), and labels
+//! are produced by a pluggable `TickFormatter`: fixed decimals, scientific, SI
+//! prefixes with a unit ("20 Hz, 1 kHz, 20 kHz"), percent, dB or any closure.
+//!
+//! Each axis has a `Scale` (linear, logarithmic with any base, symlog with a
+//! linear threshold, or a custom forward/inverse transform). Data is mapped
+//! through the scale before being handed to the `AxesModel`, whose `AxisRange`
+//! is expressed in scaled coordinates, and ticks and gridlines are placed
+//! according to the scale.
+//!
+//! Gridlines and tick marks are drawn inside the axes with `Line`. Titles and
+//! tick labels are laid out with gpui elements along the figure, the y labels
+//! starting below the title row of the figure, see `TITLE_HEIGHT`.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{multiples, nice_step, Scale, TITLE_HEIGHT};
+use gpui::{
+    div, hsla, prelude::*, px, relative, size, App, Application, Bounds, Entity, Hsla, Window,
+    WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::{AxesContext, AxesModel};
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, GeometryAxes, Line};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// Main application view containing the decorated plot
+struct AxisDecorationView {
+    figure: Entity<FigureView>,
+    x_axis: Arc<Axis>,
+    y_axis: Arc<Axis>,
+}
+
+impl AxisDecorationView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Axis Decoration - low-pass response".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        // Frequency in Hz on a log10 x axis, magnitude in dB on y
+        let x_axis = Axis::new(20.0, 20000.0)
+            .scale(Scale::Log { base: 10.0 })
+            .title("Frequency")
+            .formatter(SiFormatter::new("Hz"));
+        let y_axis = Axis::new(-30.0, 6.0)
+            .title("Magnitude")
+            .minor(2)
+            .formatter(DecibelFormatter::new(0));
+        let (x_axis, y_axis) = (Arc::new(x_axis), Arc::new(y_axis));
+
+        // Ranges are given in scaled coordinates
+        let (x_min, x_max) = x_axis.range();
+        let (y_min, y_max) = y_axis.range();
+        let x_range = AxisRange::new(x_min, x_max);
+        let y_range = AxisRange::new(y_min, y_max);
+        let axes_bounds = AxesBounds::new(x_range, y_range);
+
+        // Gridlines follow the scales and are drawn by `GridLines` instead
+        let grid = GridModel::from_numbers(1, 1);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(axes_bounds, grid)));
+
+        // Add the decorations and the response once, the figure keeps them
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |axes| {
+                axes.plot(GridLines::new(x_axis.clone(), y_axis.clone()));
+
+                // First order low-pass filter with a 1 kHz cutoff, sampled
+                // evenly along the log frequency axis
+                let mut response = Line::new().color(Hsla::blue());
+                let cutoff = 1000.0;
+                let steps = 300;
+                for i in 0..=steps {
+                    let f = x_axis.min * (x_axis.max / x_axis.min).powf(i as f64 / steps as f64);
+                    let magnitude = -10.0 * (1.0 + (f / cutoff).powi(2)).log10();
+                    response.add_point(point2(x_axis.forward(f), magnitude));
+                }
+                axes.plot(response);
+
+                axes.plot(TickMarks::new(x_axis.clone(), y_axis.clone()));
+            });
+        });
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        Self {
+            figure,
+            x_axis,
+            y_axis,
+        }
+    }
+
+    /// Tick labels of the x axis, positioned proportionally below the figure
+    fn render_x_labels(&self) -> impl IntoElement {
+        let axis = &self.x_axis;
+        div()
+            .relative()
+            .h(px(20.0))
+            .children(axis.major_ticks().into_iter().map(|tick| {
+                div()
+                    .absolute()
+                    .left(relative(axis.fraction(tick) as f32))
+                    .ml(px(-30.0))
+                    .w(px(60.0))
+                    .flex()
+                    .justify_center()
+                    .text_sm()
+                    .child(axis.format(tick))
+            }))
+    }
+
+    /// Tick labels of the y axis, positioned proportionally left of the figure
+    fn render_y_labels(&self) -> impl IntoElement {
+        let axis = &self.y_axis;
+        div()
+            .relative()
+            .w(px(64.0))
+            .flex_1()
+            .children(axis.major_ticks().into_iter().map(|tick| {
+                div()
+                    .absolute()
+                    .top(relative(1.0 - axis.fraction(tick) as f32))
+                    .mt(px(-10.0))
+                    .right(px(4.0))
+                    .text_sm()
+                    .child(axis.format(tick))
+            }))
+    }
+
+    /// Samples of each available formatter, to compare their output
+    fn render_formatters(&self) -> impl IntoElement {
+        let formatters: Vec<(&str, Box<dyn TickFormatter>)> = vec![
+            ("fixed", Box::new(FixedFormatter::new(2))),
+            ("scientific", Box::new(ScientificFormatter::new(2))),
+            ("SI", Box::new(SiFormatter::new("Hz"))),
+            ("percent", Box::new(PercentFormatter::new(0))),
+            ("dB", Box::new(DecibelFormatter::new(1))),
+            (
+                "closure",
+                Box::new(|value: f64| format!("{:.0} ms", value * 1e3)),
+            ),
+        ];
+        let values = [0.25, 1500.0, 0.004];
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .p_2()
+            .text_sm()
+            .children(formatters.into_iter().map(|(name, formatter)| {
+                let samples: Vec<String> = values.iter().map(|v| formatter.format(*v)).collect();
+                div().child(format!("{name}: {}", samples.join(", ")))
+            }))
+    }
+
+    /// Major ticks generated by each available scale
+    fn render_scales(&self) -> impl IntoElement {
+        let axes = [
+            ("linear", Axis::new(0.0, 100.0)),
+            (
+                "log10",
+                Axis::new(20.0, 20000.0).scale(Scale::Log { base: 10.0 }),
+            ),
+            (
+                "log2",
+                Axis::new(1.0, 1024.0).scale(Scale::Log { base: 2.0 }),
+            ),
+            (
+                "symlog",
+                Axis::new(-1000.0, 1000.0).scale(Scale::SymLog {
+                    base: 10.0,
+                    threshold: 10.0,
+                }),
+            ),
+            (
+                "sqrt",
+                Axis::new(0.0, 100.0).scale(Scale::Custom {
+                    forward: f64::sqrt,
+                    inverse: |position| position * position,
+                }),
+            ),
+        ];
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .p_2()
+            .text_sm()
+            .children(axes.into_iter().map(|(name, axis)| {
+                let ticks: Vec<String> = axis
+                    .major_ticks()
+                    .into_iter()
+                    .map(|tick| axis.format(tick))
+                    .collect();
+                div().child(format!("{name}: {}", ticks.join(", ")))
+            }))
+    }
+}
+
+impl Render for AxisDecorationView {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex()
+            .flex_row()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .child(div().h(px(TITLE_HEIGHT)).child(self.y_axis.title.clone()))
+                    .child(self.render_y_labels())
+                    .child(div().h(px(40.0))),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .flex()
+                    .flex_col()
+                    .child(div().flex_1().child(self.figure.clone()))
+                    .child(self.render_x_labels())
+                    .child(
+                        div()
+                            .h(px(20.0))
+                            .flex()
+                            .justify_center()
+                            .child(self.x_axis.title.clone()),
+                    ),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .child(self.render_formatters())
+                    .child(self.render_scales()),
+            )
+    }
+}
+
+/// Converts a tick value to its label
+trait TickFormatter: Send + Sync {
+    fn format(&self, value: f64) -> String;
+}
+
+impl<F: Fn(f64) -> String + Send + Sync> TickFormatter for F {
+    fn format(&self, value: f64) -> String {
+        self(value)
+    }
+}
+
+/// Fixed number of decimals, e.g. "0.25"
+struct FixedFormatter {
+    decimals: usize,
+}
+
+impl FixedFormatter {
+    fn new(decimals: usize) -> Self {
+        Self { decimals }
+    }
+}
+
+impl TickFormatter for FixedFormatter {
+    fn format(&self, value: f64) -> String {
+        format!("{:.*}", self.decimals, value)
+    }
+}
+
+/// Scientific notation, e.g. "1.50e3"
+struct ScientificFormatter {
+    decimals: usize,
+}
+
+impl ScientificFormatter {
+    fn new(decimals: usize) -> Self {
+        Self { decimals }
+    }
+}
+
+impl TickFormatter for ScientificFormatter {
+    fn format(&self, value: f64) -> String {
+        format!("{:.*e}", self.decimals, value)
+    }
+}
+
+/// SI prefixes followed by a unit, e.g. "20 Hz", "1 kHz", "2.5 MHz"
+struct SiFormatter {
+    unit: String,
+}
+
+impl SiFormatter {
+    const PREFIXES: [(f64, &'static str); 8] = [
+        (1e12, "T"),
+        (1e9, "G"),
+        (1e6, "M"),
+        (1e3, "k"),
+        (1.0, ""),
+        (1e-3, "m"),
+        (1e-6, "µ"),
+        (1e-9, "n"),
+    ];
+
+    fn new(unit: &str) -> Self {
+        Self {
+            unit: unit.to_string(),
+        }
+    }
+}
+
+impl TickFormatter for SiFormatter {
+    fn format(&self, value: f64) -> String {
+        if value == 0.0 {
+            return format!("0 {}", self.unit);
+        }
+        let (scale, prefix) = Self::PREFIXES
+            .into_iter()
+            .find(|(scale, _)| value.abs() >= *scale)
+            .unwrap_or(Self::PREFIXES[Self::PREFIXES.len() - 1]);
+        // Up to two decimals, without trailing zeros
+        let number = format!("{:.2}", value / scale);
+        let number = number.trim_end_matches('0').trim_end_matches('.');
+        format!("{number} {prefix}{}", self.unit)
+    }
+}
+
+/// Fraction shown as a percentage, e.g. "25%"
+struct PercentFormatter {
+    decimals: usize,
+}
+
+impl PercentFormatter {
+    fn new(decimals: usize) -> Self {
+        Self { decimals }
+    }
+}
+
+impl TickFormatter for PercentFormatter {
+    fn format(&self, value: f64) -> String {
+        format!("{:.*}%", self.decimals, value * 100.0)
+    }
+}
+
+/// Level in decibels, e.g. "-6 dB"
+struct DecibelFormatter {
+    decimals: usize,
+}
+
+impl DecibelFormatter {
+    fn new(decimals: usize) -> Self {
+        Self { decimals }
+    }
+}
+
+impl TickFormatter for DecibelFormatter {
+    fn format(&self, value: f64) -> String {
+        format!("{:.*} dB", self.decimals, value)
+    }
+}
+
+/// Range, scale, title and tick settings of one axis
+struct Axis {
+    min: f64,
+    max: f64,
+    scale: Scale,
+    title: String,
+    /// Approximate number of major ticks
+    major: usize,
+    /// Number of minor intervals between two major ticks
+    minor: usize,
+    formatter: Box<dyn TickFormatter>,
+}
+
+impl Axis {
+    fn new(min: f64, max: f64) -> Self {
+        Self {
+            min,
+            max,
+            scale: Scale::Linear,
+            title: String::new(),
+            major: 5,
+            minor: 4,
+            formatter: Box::new(FixedFormatter::new(1)),
+        }
+    }
+
+    /// Sets the scale of the axis, falling back to a linear scale when its
+    /// parameters are invalid, like the log ticks skip invalid ranges
+    fn scale(mut self, scale: Scale) -> Self {
+        if scale.is_valid() {
+            self.scale = scale;
+        } else {
+            tracing::warn!(
+                "invalid axis scale {scale:?}: log bases must be above 1 and symlog \
+                 thresholds positive, using a linear scale"
+            );
+            self.scale = Scale::Linear;
+        }
+        self
+    }
+
+    fn title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    fn minor(mut self, minor: usize) -> Self {
+        self.minor = minor;
+        self
+    }
+
+    fn formatter(mut self, formatter: impl TickFormatter + 'static) -> Self {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    fn format(&self, value: f64) -> String {
+        self.formatter.format(value)
+    }
+
+    /// Maps a data value to the coordinate used by the axes
+    fn forward(&self, value: f64) -> f64 {
+        self.scale.forward(value)
+    }
+
+    /// Range of the axis in axes coordinates, as given to `AxisRange`
+    fn range(&self) -> (f64, f64) {
+        (self.forward(self.min), self.forward(self.max))
+    }
+
+    /// Position of `value` along the axis, 0.0 at `min` and 1.0 at `max`
+    fn fraction(&self, value: f64) -> f64 {
+        let (lo, hi) = self.range();
+        (self.forward(value) - lo) / (hi - lo)
+    }
+
+    fn contains(&self, value: f64) -> bool {
+        let epsilon = (self.max - self.min).abs() * 1e-9;
+        value >= self.min - epsilon && value <= self.max + epsilon
+    }
+
+    fn major_ticks(&self) -> Vec<f64> {
+        match self.scale {
+            Scale::Linear => multiples(
+                self.min,
+                self.max,
+                nice_step(self.min, self.max, self.major),
+            ),
+            Scale::Log { base } => {
+                // Short ranges also get ticks at 2 and 5 times each decade
+                let decades = (self.forward(self.max) - self.forward(self.min)).abs();
+                if base == 10.0 && decades <= 3.5 {
+                    self.log_ticks(base, &[1.0, 2.0, 5.0])
+                } else {
+                    self.log_ticks(base, &[1.0])
+                }
+            }
+            Scale::SymLog { base, threshold } => {
+                let mut ticks = vec![0.0];
+                let mut magnitude = threshold;
+                while magnitude <= self.max.abs().max(self.min.abs()) {
+                    ticks.extend([-magnitude, magnitude]);
+                    magnitude *= base;
+                }
+                ticks.retain(|tick| self.contains(*tick));
+                ticks.sort_by(f64::total_cmp);
+                ticks
+            }
+            Scale::Custom { .. } => {
+                // Evenly spaced in axes coordinates
+                let (lo, hi) = self.range();
+                multiples(lo, hi, nice_step(lo, hi, self.major))
+                    .into_iter()
+                    .map(|position| self.scale.inverse(position))
+                    .collect()
+            }
+        }
+    }
+
+    /// Minor ticks, excluding the positions of major ticks
+    fn minor_ticks(&self) -> Vec<f64> {
+        match self.scale {
+            Scale::Linear if self.minor >= 2 => {
+                let step = nice_step(self.min, self.max, self.major) / self.minor as f64;
+                let first = (self.min / step).ceil() as i64;
+                multiples(self.min, self.max, step)
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(i, _)| (first + *i as i64).rem_euclid(self.minor as i64) != 0)
+                    .map(|(_, tick)| tick)
+                    .collect()
+            }
+            Scale::Log { base } if base >= 3.0 => {
+                let majors = self.major_ticks();
+                let factors: Vec<f64> = (2..base.ceil() as usize).map(|f| f as f64).collect();
+                self.log_ticks(base, &factors)
+                    .into_iter()
+                    .filter(|tick| {
+                        !majors
+                            .iter()
+                            .any(|major| (major - tick).abs() < tick * 1e-9)
+                    })
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Multiples of each power of `base` by `factors` within the axis range
+    fn log_ticks(&self, base: f64, factors: &[f64]) -> Vec<f64> {
+        // Zero and negative values have no logarithm
+        if self.min.min(self.max) <= 0.0 {
+            return Vec::new();
+        }
+        let first = self.forward(self.min.min(self.max)).floor() as i32;
+        let last = self.forward(self.max.max(self.min)).ceil() as i32;
+        (first..=last)
+            .flat_map(|power| factors.iter().map(move |factor| factor * base.powi(power)))
+            .filter(|tick| self.contains(*tick))
+            .collect()
+    }
+}
+
+/// Major and minor tick marks along the bottom and left edges of the axes
+#[derive(Clone)]
+struct TickMarks {
+    x_axis: Arc<Axis>,
+    y_axis: Arc<Axis>,
+}
+
+impl TickMarks {
+    fn new(x_axis: Arc<Axis>, y_axis: Arc<Axis>) -> Self {
+        Self { x_axis, y_axis }
+    }
+}
+
+impl GeometryAxes for TickMarks {
+    type X = f64;
+    type Y = f64;
+
+    fn render_axes(&mut self, cx: &mut AxesContext<Self::X, Self::Y>) {
+        let (x, y) = (&self.x_axis, &self.y_axis);
+        let (x_range, y_range) = (x.range(), y.range());
+        // Tick lengths as a fraction of the other axis span
+        let x_length = (y_range.1 - y_range.0) * 0.02;
+        let y_length = (x_range.1 - x_range.0) * 0.015;
+
+        for (ticks, scale) in [(x.major_ticks(), 1.0), (x.minor_ticks(), 0.5)] {
+            for tick in ticks {
+                let tick = x.forward(tick);
+                let bottom = y_range.0;
+                draw_segment(
+                    cx,
+                    Hsla::black(),
+                    (tick, bottom),
+                    (tick, bottom + x_length * scale),
+                );
+            }
+        }
+        for (ticks, scale) in [(y.major_ticks(), 1.0), (y.minor_ticks(), 0.5)] {
+            for tick in ticks {
+                let tick = y.forward(tick);
+                let left = x_range.0;
+                draw_segment(
+                    cx,
+                    Hsla::black(),
+                    (left, tick),
+                    (left + y_length * scale, tick),
+                );
+            }
+        }
+    }
+}
+
+/// Gridlines at the major and minor ticks of both axes, following their scales
+#[derive(Clone)]
+struct GridLines {
+    x_axis: Arc<Axis>,
+    y_axis: Arc<Axis>,
+}
+
+impl GridLines {
+    fn new(x_axis: Arc<Axis>, y_axis: Arc<Axis>) -> Self {
+        Self { x_axis, y_axis }
+    }
+}
+
+impl GeometryAxes for GridLines {
+    type X = f64;
+    type Y = f64;
+
+    fn render_axes(&mut self, cx: &mut AxesContext<Self::X, Self::Y>) {
+        let (x, y) = (&self.x_axis, &self.y_axis);
+        let (x_range, y_range) = (x.range(), y.range());
+        let major = hsla(0.0, 0.0, 0.75, 1.0);
+        let minor = hsla(0.0, 0.0, 0.9, 1.0);
+
+        for (ticks, color) in [(x.minor_ticks(), minor), (x.major_ticks(), major)] {
+            for tick in ticks {
+                let tick = x.forward(tick);
+                draw_segment(cx, color, (tick, y_range.0), (tick, y_range.1));
+            }
+        }
+        for (ticks, color) in [(y.minor_ticks(), minor), (y.major_ticks(), major)] {
+            for tick in ticks {
+                let tick = y.forward(tick);
+                draw_segment(cx, color, (x_range.0, tick), (x_range.1, tick));
+            }
+        }
+    }
+}
+
+/// Draws a straight segment in axes coordinates
+fn draw_segment(cx: &mut AxesContext<f64, f64>, color: Hsla, from: (f64, f64), to: (f64, f64)) {
+    let mut line = Line::new().color(color);
+    line.add_point(point2(from.0, from.1));
+    line.add_point(point2(to.0, to.1));
+    line.render_axes(cx);
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(900.0), px(600.0)), cx);
+
+        // Open the main window with our decorated plot
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| AxisDecorationView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
@@ -0,0 +1,512 @@
+//! Bar Chart Example
+//!
+//! This example demonstrates how to draw bar charts with a `Bar` geometry
+//! implementing `GeometryAxes`. A `Bar` holds one or more `BarSeries` with a
+//! value per category, and lays them out either grouped side by side or
+//! stacked on top of each other, vertically or horizontally.
+//!
+//! Categories are placed at integer positions on the category axis, and the
+//! width of the bars is a fraction of the spacing between categories. Bars
+//! start at a configurable baseline and values are their length from it, in
+//! both layouts, and single bars can be given their own color. Bar outlines
+//! are plotted in the axes, while their insides are filled in a lighter shade
+//! of their color with the shared `fill_layer`, and the values are optionally
+//! shown as labels at the value end of each bar: past it for grouped bars,
+//! on the side the bar grows towards, and just inside it for stacked bars.
+//!
+//! The data is a weekly report of build times, in minutes.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{bounds_tracker, fill_layer, ElementBounds, FigureArea, Polygon};
+use gpui::{
+    div, prelude::*, px, size, App, Application, Bounds, ClickEvent, Entity, Hsla, Window,
+    WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::{AxesContext, AxesModel};
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, GeometryAxes, Line};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// Width of the boxes value labels are aligned in, in pixels
+const VALUE_LABEL_WIDTH: f32 = 48.0;
+
+/// Main application view containing the bar chart
+struct BarChartView {
+    axes_model: Arc<RwLock<AxesModel<f64, f64>>>,
+    figure: Entity<FigureView>,
+    categories: Vec<String>,
+    chart: Bar,
+    /// Bounds of the figure element, updated on every paint
+    element_bounds: ElementBounds,
+}
+
+impl BarChartView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Bar Chart - weekly build times".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        let categories: Vec<String> = (1..=6).map(|week| format!("W{week}")).collect();
+        let chart = Bar::new()
+            .width(0.8)
+            .labels(true)
+            .add_series(
+                BarSeries::new("compile", vec![7.5, 8.1, 8.4, 12.9, 9.0, 8.7], Hsla::blue())
+                    // The week the cache was broken
+                    .bar_color(3, Hsla::red()),
+            )
+            .add_series(BarSeries::new(
+                "link",
+                vec![1.2, 1.3, 1.1, 1.4, 1.2, 0.9],
+                Hsla::green(),
+            ))
+            .add_series(BarSeries::new(
+                "test",
+                vec![4.0, 4.4, 5.1, 4.8, 5.6, 5.2],
+                Hsla::black(),
+            ));
+
+        let grid = GridModel::from_numbers(10, 8);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(chart.bounds(), grid)));
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |axes| {
+                axes.plot(chart.clone());
+            });
+        });
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        Self {
+            axes_model,
+            figure,
+            categories,
+            chart,
+            element_bounds: ElementBounds::default(),
+        }
+    }
+
+    /// Applies `f` to the chart, plots it again and fits the axes to the new
+    /// layout
+    fn update(&mut self, cx: &mut Context<Self>, f: impl FnOnce(&mut Bar)) {
+        f(&mut self.chart);
+        let mut axes = self.axes_model.write();
+        axes.bounds = self.chart.bounds();
+        axes.clear_elements();
+        axes.plot(self.chart.clone());
+        cx.notify();
+    }
+
+    fn render_button(
+        &self,
+        id: &'static str,
+        label: String,
+        cx: &mut Context<Self>,
+        action: fn(&mut Bar),
+    ) -> impl IntoElement {
+        div()
+            .id(id)
+            .px_2()
+            .border_1()
+            .border_color(Hsla::black())
+            .cursor_pointer()
+            .on_click(cx.listener(move |this, _: &ClickEvent, _window, cx| {
+                this.update(cx, action);
+            }))
+            .child(label)
+    }
+
+    /// Value and category labels, placed from the bounds of the figure element
+    fn render_labels(&self) -> Option<impl IntoElement> {
+        let area = FigureArea::new(&self.element_bounds)?;
+        let (x_range, y_range) = self.chart.extent();
+        let (x0, y0) = (x_range.0, y_range.0);
+        let label = |position: (f64, f64), offset: (f32, f32), text: String| {
+            let (left, top) = area.data_offset(position, x_range, y_range);
+            div()
+                .absolute()
+                .left(px(left + offset.0))
+                .top(px(top + offset.1))
+                .text_sm()
+                .child(text)
+        };
+
+        // Value labels centered on the bars at their value end, past it for
+        // grouped bars and just inside it for stacked bars, so that they stay
+        // on their own segment. Negative bars grow the other way
+        let (lowest, name_offset) = match self.chart.orientation {
+            Orientation::Vertical => (y0, (-8.0, -22.0)),
+            Orientation::Horizontal => (x0, (4.0, -8.0)),
+        };
+        let rects = if self.chart.labels {
+            self.chart.rects()
+        } else {
+            Vec::new()
+        };
+        let stacked = self.chart.layout == BarLayout::Stacked;
+        let values = rects.into_iter().map(|rect| {
+            let center = (rect.start + rect.end) / 2.0;
+            let position = self.chart.to_xy(center, rect.to);
+            let (x, y) = area.data_offset(position, x_range, y_range);
+            let forward = (rect.to >= rect.from) != stacked;
+            let text = div()
+                .absolute()
+                .w(px(VALUE_LABEL_WIDTH))
+                .flex()
+                .text_sm()
+                .child(format!("{:.1}", rect.value));
+            let half = VALUE_LABEL_WIDTH / 2.0;
+            match (self.chart.orientation, forward) {
+                (Orientation::Vertical, true) => {
+                    text.justify_center().left(px(x - half)).top(px(y - 18.0))
+                }
+                (Orientation::Vertical, false) => {
+                    text.justify_center().left(px(x - half)).top(px(y + 2.0))
+                }
+                (Orientation::Horizontal, true) => {
+                    text.justify_start().left(px(x + 4.0)).top(px(y - 9.0))
+                }
+                (Orientation::Horizontal, false) => text
+                    .justify_end()
+                    .left(px(x - 4.0 - VALUE_LABEL_WIDTH))
+                    .top(px(y - 9.0)),
+            }
+        });
+
+        // Category names just inside the lower end of the value axis
+        let names = self.categories.iter().enumerate().map(|(index, name)| {
+            let position = self.chart.to_xy(index as f64, lowest);
+            label(position, name_offset, name.clone())
+        });
+
+        Some(
+            div()
+                .absolute()
+                .size_full()
+                .children(values)
+                .children(names),
+        )
+    }
+}
+
+impl Render for BarChartView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let buttons = [
+            self.render_button(
+                "orientation",
+                format!("{:?}", self.chart.orientation),
+                cx,
+                |chart| {
+                    chart.orientation = match chart.orientation {
+                        Orientation::Vertical => Orientation::Horizontal,
+                        Orientation::Horizontal => Orientation::Vertical,
+                    }
+                },
+            ),
+            self.render_button("layout", format!("{:?}", self.chart.layout), cx, |chart| {
+                chart.layout = match chart.layout {
+                    BarLayout::Grouped => BarLayout::Stacked,
+                    BarLayout::Stacked => BarLayout::Grouped,
+                }
+            }),
+            self.render_button(
+                "baseline",
+                format!("Baseline: {}", self.chart.baseline),
+                cx,
+                |chart| chart.baseline = if chart.baseline == 0.0 { 5.0 } else { 0.0 },
+            ),
+            self.render_button(
+                "labels",
+                format!("Labels: {}", self.chart.labels),
+                cx,
+                |chart| chart.labels = !chart.labels,
+            ),
+        ];
+        let legend = self.chart.series.iter().map(|series| {
+            div()
+                .flex()
+                .flex_row()
+                .items_center()
+                .gap_1()
+                .child(div().w(px(12.0)).h(px(12.0)).bg(series.color))
+                .child(series.label.clone())
+        });
+
+        // Record the bounds of the figure element to place the labels
+        let tracker = bounds_tracker(&self.element_bounds, cx.entity_id());
+        let (x, y) = self.chart.extent();
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_2()
+                    .p_2()
+                    .children(buttons)
+                    .children(legend),
+            )
+            .child(
+                div()
+                    .relative()
+                    .size_full()
+                    .child(self.figure.clone())
+                    .child(fill_layer(self.chart.fills(), x, y))
+                    .child(tracker)
+                    .children(self.render_labels()),
+            )
+    }
+}
+
+/// Direction in which the bars grow
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Orientation {
+    Vertical,
+    Horizontal,
+}
+
+/// How the bars of several series share a category
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BarLayout {
+    /// Side by side, each starting at the baseline
+    Grouped,
+    /// On top of each other from the baseline, positive values above and
+    /// negative values below it
+    Stacked,
+}
+
+/// One value per category, with an optional color per bar
+#[derive(Clone)]
+struct BarSeries {
+    label: String,
+    values: Vec<f64>,
+    color: Hsla,
+    bar_colors: Vec<(usize, Hsla)>,
+}
+
+impl BarSeries {
+    fn new(label: &str, values: Vec<f64>, color: Hsla) -> Self {
+        Self {
+            label: label.to_string(),
+            values,
+            color,
+            bar_colors: Vec::new(),
+        }
+    }
+
+    /// Overrides the color of the bar of category `index`
+    fn bar_color(mut self, index: usize, color: Hsla) -> Self {
+        self.bar_colors.push((index, color));
+        self
+    }
+
+    fn color_of(&self, index: usize) -> Hsla {
+        self.bar_colors
+            .iter()
+            .rev()
+            .find(|(i, _)| *i == index)
+            .map_or(self.color, |(_, color)| *color)
+    }
+}
+
+/// A bar, in category and value coordinates
+#[derive(Clone, Copy, Debug)]
+struct BarRect {
+    /// Extent along the category axis
+    start: f64,
+    end: f64,
+    /// Extent along the value axis
+    from: f64,
+    to: f64,
+    value: f64,
+    color: Hsla,
+}
+
+/// Bars of one or more series, grouped or stacked
+#[derive(Clone)]
+struct Bar {
+    series: Vec<BarSeries>,
+    orientation: Orientation,
+    layout: BarLayout,
+    /// Width of a category's bars, as a fraction of the category spacing
+    width: f64,
+    /// Value axis position the bars start from, values being their length
+    baseline: f64,
+    labels: bool,
+}
+
+impl Bar {
+    fn new() -> Self {
+        Self {
+            series: Vec::new(),
+            orientation: Orientation::Vertical,
+            layout: BarLayout::Grouped,
+            width: 0.8,
+            baseline: 0.0,
+            labels: false,
+        }
+    }
+
+    fn add_series(mut self, series: BarSeries) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    fn width(mut self, width: f64) -> Self {
+        self.width = width.clamp(0.0, 1.0);
+        self
+    }
+
+    fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    fn categories(&self) -> usize {
+        self.series
+            .iter()
+            .map(|series| series.values.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Maps category and value coordinates to x and y
+    fn to_xy(&self, category: f64, value: f64) -> (f64, f64) {
+        match self.orientation {
+            Orientation::Vertical => (category, value),
+            Orientation::Horizontal => (value, category),
+        }
+    }
+
+    /// Bars of all series, with category `i` centered at `i`
+    fn rects(&self) -> Vec<BarRect> {
+        let count = self.series.len().max(1) as f64;
+        let mut rects = Vec::new();
+        for category in 0..self.categories() {
+            let left = category as f64 - self.width / 2.0;
+            let (mut above, mut below) = (self.baseline, self.baseline);
+            for (index, series) in self.series.iter().enumerate() {
+                let Some(&value) = series.values.get(category) else {
+                    continue;
+                };
+                let (start, end, from, to) = match self.layout {
+                    BarLayout::Grouped => {
+                        let step = self.width / count;
+                        let start = left + index as f64 * step;
+                        (start, start + step, self.baseline, self.baseline + value)
+                    }
+                    BarLayout::Stacked if value >= 0.0 => {
+                        above += value;
+                        (left, left + self.width, above - value, above)
+                    }
+                    BarLayout::Stacked => {
+                        below += value;
+                        (left, left + self.width, below - value, below)
+                    }
+                };
+                rects.push(BarRect {
+                    start,
+                    end,
+                    from,
+                    to,
+                    value,
+                    color: series.color_of(category),
+                });
+            }
+        }
+        rects
+    }
+
+    /// X and y ranges fitting all bars, with room for the labels
+    fn extent(&self) -> ((f64, f64), (f64, f64)) {
+        let categories = (-0.5, self.categories().max(1) as f64 - 0.5);
+        let (low, high) =
+            self.rects()
+                .iter()
+                .fold((self.baseline, self.baseline), |(low, high), rect| {
+                    (
+                        low.min(rect.from.min(rect.to)),
+                        high.max(rect.from.max(rect.to)),
+                    )
+                });
+        let padding = (high - low).max(1.0) * 0.1;
+        let values = (low - padding, high + padding);
+        match self.orientation {
+            Orientation::Vertical => (categories, values),
+            Orientation::Horizontal => (values, categories),
+        }
+    }
+
+    /// Insides of the bars, in a lighter shade of their color
+    fn fills(&self) -> Vec<Polygon> {
+        self.rects()
+            .into_iter()
+            .map(|rect| {
+                let color = Hsla {
+                    a: 0.4,
+                    ..rect.color
+                };
+                let from = self.to_xy(rect.start, rect.from);
+                let to = self.to_xy(rect.end, rect.to);
+                Polygon::rect(from, to, color)
+            })
+            .collect()
+    }
+
+    fn bounds(&self) -> AxesBounds<f64, f64> {
+        let (x, y) = self.extent();
+        AxesBounds::new(AxisRange::new(x.0, x.1), AxisRange::new(y.0, y.1))
+    }
+}
+
+impl GeometryAxes for Bar {
+    type X = f64;
+    type Y = f64;
+
+    /// Draws the outlines only, the insides are painted by `fill_layer`
+    fn render_axes(&mut self, cx: &mut AxesContext<Self::X, Self::Y>) {
+        for rect in self.rects() {
+            let mut outline = Line::new().color(rect.color);
+            for (category, value) in [
+                (rect.start, rect.from),
+                (rect.start, rect.to),
+                (rect.end, rect.to),
+                (rect.end, rect.from),
+                (rect.start, rect.from),
+            ] {
+                let (x, y) = self.to_xy(category, value);
+                outline.add_point(point2(x, y));
+            }
+            outline.render_axes(cx);
+        }
+    }
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our bar chart
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| BarChartView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
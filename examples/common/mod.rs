@@ -0,0 +1,617 @@
+//! Helpers shared by the examples
+//!
+//! Tick steps and axis scales used by the examples drawing their own axes,
+//! the mapping between the pixels of a figure element and its axes used by
+//! the interactive examples, and filled polygons and strokes painted over a
+//! figure. Each example includes this module with `mod common;` and only uses
+//! part of it.
+//!
+//! gpui-plot does not expose where `FigureView` lays out its axes. Everything
+//! placed over a figure here assumes the axes fill the figure element below
+//! a title row of `TITLE_HEIGHT` pixels, which is where `FigureView` draws
+//! the title of the `FigureModel`.
+
+#![allow(dead_code)]
+
+use gpui::{
+    canvas, point, prelude::*, px, size, Bounds, ContentMask, EntityId, Hsla, PathBuilder, Pixels,
+    Point, Window,
+};
+use std::cell::Cell;
+use std::f32::consts::PI;
+use std::rc::Rc;
+
+/// Height of the row above the axes where `FigureView` draws the title of the
+/// figure, in pixels: one line of text at the default size
+pub const TITLE_HEIGHT: f32 = 26.0;
+
+/// Bounds of a figure element, updated on every paint
+pub type ElementBounds = Rc<Cell<Option<Bounds<Pixels>>>>;
+
+/// Invisible element filling its parent, which records the bounds of the
+/// parent into `element_bounds` and renders `view` again when they change, so
+/// that anything placed from them follows a resize
+pub fn bounds_tracker(element_bounds: &ElementBounds, view: EntityId) -> impl IntoElement {
+    let element_bounds = element_bounds.clone();
+    canvas(
+        move |bounds, _window, cx| {
+            if element_bounds.replace(Some(bounds)) != Some(bounds) {
+                cx.defer(move |app| app.notify(view));
+            }
+        },
+        |_bounds, _state, _window, _cx| {},
+    )
+    .absolute()
+    .size_full()
+}
+
+/// Mapping between the pixels of a figure element and positions along its
+/// axes, which fill the element below the title row, see `TITLE_HEIGHT`
+#[derive(Clone, Copy, Debug)]
+pub struct FigureArea {
+    bounds: Bounds<Pixels>,
+}
+
+impl FigureArea {
+    /// Area of the recorded bounds, `None` before the first paint or while
+    /// the element is empty
+    pub fn new(element_bounds: &ElementBounds) -> Option<Self> {
+        Self::from_bounds(element_bounds.get()?)
+    }
+
+    /// Area of the given element bounds, `None` while they leave no room
+    /// for the axes
+    pub fn from_bounds(bounds: Bounds<Pixels>) -> Option<Self> {
+        let area = Self { bounds };
+        (area.width() > 0.0 && area.height() > 0.0).then_some(area)
+    }
+
+    /// Width of the axes, in pixels
+    pub fn width(&self) -> f32 {
+        f32::from(self.bounds.size.width)
+    }
+
+    /// Height of the axes, in pixels, below the title row
+    pub fn height(&self) -> f32 {
+        f32::from(self.bounds.size.height) - TITLE_HEIGHT
+    }
+
+    /// Bounds of the axes in window pixels
+    pub fn axes(&self) -> Bounds<Pixels> {
+        Bounds {
+            origin: point(
+                self.bounds.origin.x,
+                self.bounds.origin.y + px(TITLE_HEIGHT),
+            ),
+            size: size(self.bounds.size.width, px(self.height())),
+        }
+    }
+
+    /// Offset of a window position from the top left corner of the element,
+    /// to place gpui elements over the figure
+    pub fn local(&self, position: Point<Pixels>) -> (f32, f32) {
+        (
+            f32::from(position.x) - f32::from(self.bounds.origin.x),
+            f32::from(position.y) - f32::from(self.bounds.origin.y),
+        )
+    }
+
+    /// Distance of a window position from the left and bottom edges of the
+    /// axes, in pixels
+    pub fn edge_distance(&self, position: Point<Pixels>) -> (f32, f32) {
+        let (left, top) = self.local(position);
+        (left, TITLE_HEIGHT + self.height() - top)
+    }
+
+    /// Window position as fractions of the axes, from 0 at the left and
+    /// bottom edges to 1 at the right and top edges
+    pub fn fraction(&self, position: Point<Pixels>) -> (f64, f64) {
+        let (left, bottom) = self.edge_distance(position);
+        (
+            (left / self.width()) as f64,
+            (bottom / self.height()) as f64,
+        )
+    }
+
+    /// Offset from the top left corner of the element of the point at the
+    /// given fractions of the axes, to place gpui elements over the figure
+    pub fn offset(&self, (fx, fy): (f64, f64)) -> (f32, f32) {
+        (
+            fx as f32 * self.width(),
+            TITLE_HEIGHT + (1.0 - fy) as f32 * self.height(),
+        )
+    }
+
+    /// Data coordinates of a window position, given the x and y ranges of the
+    /// axes
+    pub fn data(&self, position: Point<Pixels>, x: (f64, f64), y: (f64, f64)) -> (f64, f64) {
+        let (fx, fy) = self.fraction(position);
+        (lerp(x, fx), lerp(y, fy))
+    }
+
+    /// Offset from the top left corner of the element of a point in data
+    /// coordinates, given the x and y ranges of the axes
+    pub fn data_offset(&self, (px, py): (f64, f64), x: (f64, f64), y: (f64, f64)) -> (f32, f32) {
+        self.offset((fraction(x, px), fraction(y, py)))
+    }
+
+    /// Window position of a point in data coordinates, given the x and y
+    /// ranges of the axes
+    pub fn data_point(&self, data: (f64, f64), x: (f64, f64), y: (f64, f64)) -> Point<Pixels> {
+        let (left, top) = self.data_offset(data, x, y);
+        point(
+            self.bounds.origin.x + px(left),
+            self.bounds.origin.y + px(top),
+        )
+    }
+}
+
+/// Region in data coordinates bounded by one or more closed rings and filled
+/// with a single color, a point being inside when it is within an odd number
+/// of rings
+#[derive(Clone, Debug)]
+pub struct Polygon {
+    pub rings: Vec<Vec<(f64, f64)>>,
+    pub color: Hsla,
+}
+
+impl Polygon {
+    pub fn new(points: Vec<(f64, f64)>, color: Hsla) -> Self {
+        Self::with_rings(vec![points], color)
+    }
+
+    /// Region made of several rings, e.g. disjoint pieces painted as one
+    /// path, which avoids seams between pieces sharing an edge
+    pub fn with_rings(rings: Vec<Vec<(f64, f64)>>, color: Hsla) -> Self {
+        Self { rings, color }
+    }
+
+    /// Rectangle with opposite corners `from` and `to`
+    pub fn rect(from: (f64, f64), to: (f64, f64), color: Hsla) -> Self {
+        Self::new(vec![from, (to.0, from.1), to, (from.0, to.1)], color)
+    }
+}
+
+/// Invisible element filling its parent, which holds the figure, that calls
+/// `paint` with the window and the mapping from data coordinates to window
+/// pixels given the x and y ranges of the axes. `FigureView` only draws thin
+/// lines, so filled shapes and strokes sized in pixels are painted on top of
+/// it, clipped to the axes
+pub fn paint_layer(
+    x: (f64, f64),
+    y: (f64, f64),
+    paint: impl FnOnce(&mut Window, &dyn Fn((f64, f64)) -> Point<Pixels>) + 'static,
+) -> impl IntoElement {
+    canvas(
+        |_bounds, _window, _cx| {},
+        move |bounds, _state, window, _cx| {
+            let Some(area) = FigureArea::from_bounds(bounds) else {
+                return;
+            };
+            let to_pixels = |data| area.data_point(data, x, y);
+            let bounds = area.axes();
+            window.with_content_mask(Some(ContentMask { bounds }), |window| {
+                paint(window, &to_pixels)
+            });
+        },
+    )
+    .absolute()
+    .size_full()
+}
+
+/// Paints `polygons` over the figure, see `paint_layer`
+pub fn fill_layer(polygons: Vec<Polygon>, x: (f64, f64), y: (f64, f64)) -> impl IntoElement {
+    paint_layer(x, y, move |window, to_pixels| {
+        for polygon in &polygons {
+            let rings = polygon
+                .rings
+                .iter()
+                .map(|ring| ring.iter().map(|&data| to_pixels(data)).collect());
+            fill_path(window, rings, polygon.color);
+        }
+    })
+}
+
+/// Fills the region bounded by closed `rings` of window pixels with the
+/// even-odd rule. Rings with fewer than three points or with non-finite
+/// points, e.g. outside of a log axis, are skipped
+pub fn fill_path(
+    window: &mut Window,
+    rings: impl IntoIterator<Item = Vec<Point<Pixels>>>,
+    color: Hsla,
+) {
+    let finite = |p: &Point<Pixels>| f32::from(p.x).is_finite() && f32::from(p.y).is_finite();
+    let mut builder = PathBuilder::fill();
+    let mut empty = true;
+    for ring in rings {
+        let [first, rest @ ..] = ring.as_slice() else {
+            continue;
+        };
+        if rest.len() < 2 || !ring.iter().all(finite) {
+            continue;
+        }
+        builder.move_to(*first);
+        for &point in rest {
+            builder.line_to(point);
+        }
+        builder.close();
+        empty = false;
+    }
+    if empty {
+        return;
+    }
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+    }
+}
+
+/// Shape of the open ends of a stroke
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineCap {
+    /// Cut square at the end point
+    Butt,
+    /// Half disc centered on the end point
+    Round,
+    /// Cut square half the width past the end point
+    Square,
+}
+
+/// Shape of the outer corner where two segments of a stroke meet
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineJoin {
+    /// Sharp corner, beveled past `MITER_LIMIT`
+    Miter,
+    /// Arc centered on the corner
+    Round,
+    /// Corner cut straight across
+    Bevel,
+}
+
+/// Longest miter join, as a ratio of its length to the stroke width as in
+/// SVG, sharper corners are beveled
+pub const MITER_LIMIT: f32 = 4.0;
+
+/// Polyline in data coordinates, stroked with a width and dashes in pixels
+#[derive(Clone, Debug)]
+pub struct Stroke {
+    pub points: Vec<(f64, f64)>,
+    pub color: Hsla,
+    pub width: f32,
+    pub cap: LineCap,
+    pub join: LineJoin,
+    /// Alternating on and off lengths in pixels, solid when empty
+    pub dashes: Vec<f32>,
+}
+
+impl Stroke {
+    /// Solid stroke 1 pixel wide with butt caps and miter joins
+    pub fn new(points: Vec<(f64, f64)>, color: Hsla) -> Self {
+        Self {
+            points,
+            color,
+            width: 1.0,
+            cap: LineCap::Butt,
+            join: LineJoin::Miter,
+            dashes: Vec::new(),
+        }
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn cap(mut self, cap: LineCap) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    pub fn join(mut self, join: LineJoin) -> Self {
+        self.join = join;
+        self
+    }
+
+    pub fn dashes(mut self, dashes: Vec<f32>) -> Self {
+        self.dashes = dashes;
+        self
+    }
+
+    /// Outline of a run of window pixels: the left side, the end cap, the
+    /// right side backwards and the start cap
+    fn outline(&self, run: &[(f32, f32)]) -> Option<Vec<(f32, f32)>> {
+        if !self.width.is_finite() || self.width <= 0.0 {
+            return None;
+        }
+        let half = self.width / 2.0;
+        let mut points = run.to_vec();
+        points.dedup_by(|a, b| distance(*a, *b) < 1e-3);
+        let (&first, &last) = (points.first()?, points.last()?);
+        if points.len() == 1 {
+            // A dash of zero length, only visible as a dot with round caps
+            let dot = arc(first, (half, 0.0), 2.0 * PI);
+            return (self.cap == LineCap::Round).then(|| dot.collect());
+        }
+
+        let directions: Vec<_> = points
+            .windows(2)
+            .map(|segment| unit(sub(segment[1], segment[0])))
+            .collect();
+        let (start, end) = (directions[0], directions[directions.len() - 1]);
+        if self.cap == LineCap::Square {
+            points[0] = sub(first, scale(start, half));
+            let end_point = add(last, scale(end, half));
+            *points.last_mut()? = end_point;
+        }
+
+        let mut ring = self.side(&points, &directions, 1.0);
+        if self.cap == LineCap::Round {
+            ring.extend(arc(last, scale(normal(end), half), -PI).skip(1));
+        }
+        let mut right = self.side(&points, &directions, -1.0);
+        right.reverse();
+        ring.extend(right);
+        if self.cap == LineCap::Round {
+            ring.extend(arc(first, scale(normal(start), -half), -PI).skip(1));
+        }
+        Some(ring)
+    }
+
+    /// Offset of the run to the left of its direction for `side` 1.0 and to
+    /// the right for -1.0, with the join on the outer side of each corner
+    fn side(&self, points: &[(f32, f32)], directions: &[(f32, f32)], side: f32) -> Vec<(f32, f32)> {
+        let half = side * self.width / 2.0;
+        let offset = |point, direction| add(point, scale(normal(direction), half));
+        let mut offsets = vec![offset(points[0], directions[0])];
+        for (i, pair) in directions.windows(2).enumerate() {
+            let (point, d0, d1) = (points[i + 1], pair[0], pair[1]);
+            let (n0, n1) = (normal(d0), normal(d1));
+            let cross = d0.0 * d1.1 - d0.1 * d1.0;
+            let cosine = dot(n0, n1);
+            // Length of the miter over the stroke width, infinite on U-turns
+            let ratio = (2.0 / (1.0 + cosine)).sqrt();
+            let miter = add(point, scale(add(n0, n1), half / (1.0 + cosine)));
+            let (before, after) = (offset(point, d0), offset(point, d1));
+            if cross.abs() < 1e-6 && cosine > 0.0 {
+                offsets.push(before);
+            } else if side * cross > 0.0 {
+                // Inner side, where both offsets overlap
+                if ratio <= MITER_LIMIT {
+                    offsets.push(miter);
+                } else {
+                    offsets.extend([before, after]);
+                }
+            } else {
+                match self.join {
+                    LineJoin::Miter if ratio <= MITER_LIMIT => offsets.push(miter),
+                    LineJoin::Round => {
+                        let turn = cross.atan2(dot(d0, d1));
+                        offsets.extend(arc(point, scale(n0, half), turn));
+                    }
+                    _ => offsets.extend([before, after]),
+                }
+            }
+        }
+        offsets.push(offset(
+            points[points.len() - 1],
+            directions[directions.len() - 1],
+        ));
+        offsets
+    }
+}
+
+/// Paints `strokes` over the figure, see `paint_layer`
+pub fn stroke_layer(strokes: Vec<Stroke>, x: (f64, f64), y: (f64, f64)) -> impl IntoElement {
+    paint_layer(x, y, move |window, to_pixels| {
+        for stroke in &strokes {
+            let points: Vec<_> = stroke.points.iter().map(|&data| to_pixels(data)).collect();
+            stroke_path(window, &points, stroke);
+        }
+    })
+}
+
+/// Strokes the polyline through `points`, in window pixels, with the color,
+/// width, caps, joins and dashes of `stroke`, whose own points are not used.
+/// Non-finite points split the polyline
+pub fn stroke_path(window: &mut Window, points: &[Point<Pixels>], stroke: &Stroke) {
+    let points: Vec<_> = points
+        .iter()
+        .map(|point| (f32::from(point.x), f32::from(point.y)))
+        .collect();
+    let finite = |p: &(f32, f32)| p.0.is_finite() && p.1.is_finite();
+    for part in points.split(|p| !finite(p)) {
+        // Dashes are painted one by one, so that overlapping dashes do not
+        // cancel out
+        for run in dash(part, &stroke.dashes) {
+            let Some(outline) = stroke.outline(&run) else {
+                continue;
+            };
+            let ring = outline.into_iter().map(|(x, y)| point(px(x), px(y)));
+            fill_path(window, [ring.collect()], stroke.color);
+        }
+    }
+}
+
+/// Splits a polyline into the runs drawn by alternating on and off
+/// `lengths`, an odd number of lengths being repeated. Invalid patterns draw
+/// the whole polyline
+pub fn dash(points: &[(f32, f32)], lengths: &[f32]) -> Vec<Vec<(f32, f32)>> {
+    let valid = lengths.iter().all(|length| *length >= 0.0) && lengths.iter().sum::<f32>() > 0.0;
+    if !valid || points.len() < 2 {
+        return vec![points.to_vec()];
+    }
+    let pattern = if lengths.len() % 2 == 1 {
+        [lengths, lengths].concat()
+    } else {
+        lengths.to_vec()
+    };
+
+    let mut runs = Vec::new();
+    let mut current = vec![points[0]];
+    let mut index = 0;
+    let mut remaining = pattern[0];
+    for segment in points.windows(2) {
+        let (a, b) = (segment[0], segment[1]);
+        let length = distance(a, b);
+        let mut travelled = 0.0;
+        while length - travelled > remaining {
+            travelled += remaining;
+            let split = add(a, scale(sub(b, a), travelled / length));
+            if index % 2 == 0 {
+                current.push(split);
+                runs.push(std::mem::take(&mut current));
+            } else {
+                current = vec![split];
+            }
+            index = (index + 1) % pattern.len();
+            remaining = pattern[index];
+        }
+        remaining -= length - travelled;
+        if index % 2 == 0 {
+            current.push(b);
+        }
+    }
+    if index % 2 == 0 {
+        runs.push(current);
+    }
+    runs
+}
+
+/// Points of an arc around `center`, from `center + start` and turning by
+/// `angle` radians, positive from the x axis towards the y axis
+fn arc(center: (f32, f32), start: (f32, f32), angle: f32) -> impl Iterator<Item = (f32, f32)> {
+    let steps = (ROUND_STEPS as f32 * angle.abs() / PI).ceil().max(1.0) as usize;
+    (0..=steps).map(move |step| {
+        let (sin, cos) = (angle * step as f32 / steps as f32).sin_cos();
+        let rotated = (start.0 * cos - start.1 * sin, start.0 * sin + start.1 * cos);
+        add(center, rotated)
+    })
+}
+
+/// Segments of round caps and joins per half turn
+const ROUND_STEPS: usize = 8;
+
+fn add(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+    (a.0 + b.0, a.1 + b.1)
+}
+
+fn sub(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+fn scale(a: (f32, f32), factor: f32) -> (f32, f32) {
+    (a.0 * factor, a.1 * factor)
+}
+
+fn dot(a: (f32, f32), b: (f32, f32)) -> f32 {
+    a.0 * b.0 + a.1 * b.1
+}
+
+fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    (b.0 - a.0).hypot(b.1 - a.1)
+}
+
+fn unit(a: (f32, f32)) -> (f32, f32) {
+    scale(a, 1.0 / a.0.hypot(a.1))
+}
+
+/// Direction turned by a quarter from `a`, on its left in a y-up frame
+fn normal(a: (f32, f32)) -> (f32, f32) {
+    (-a.1, a.0)
+}
+
+/// Position of `value` in a range, 0 at `min` and 1 at `max`
+pub fn fraction((min, max): (f64, f64), value: f64) -> f64 {
+    (value - min) / (max - min)
+}
+
+/// Value at `fraction` of a range, `min` at 0 and `max` at 1
+pub fn lerp((min, max): (f64, f64), fraction: f64) -> f64 {
+    min + (max - min) * fraction
+}
+
+/// Distance between about `count` ticks, rounded to 1, 2 or 5 times a power of ten
+pub fn nice_step(min: f64, max: f64, count: usize) -> f64 {
+    let raw = (max - min).abs() / count.max(1) as f64;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let factor = [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .find(|factor| factor * magnitude >= raw)
+        .unwrap_or(10.0);
+    factor * magnitude
+}
+
+/// Multiples of `step` between `min` and `max`
+pub fn multiples(min: f64, max: f64, step: f64) -> Vec<f64> {
+    if !step.is_finite() || step <= 0.0 {
+        return Vec::new();
+    }
+    let epsilon = step * 1e-9;
+    let first = ((min - epsilon) / step).ceil() as i64;
+    let last = ((max + epsilon) / step).floor() as i64;
+    (first..=last).map(|i| i as f64 * step).collect()
+}
+
+/// Mapping between data values and positions along an axis
+#[derive(Clone, Copy, Debug)]
+pub enum Scale {
+    Linear,
+    Log {
+        base: f64,
+    },
+    /// Linear within `[-threshold, threshold]` and logarithmic outside
+    SymLog {
+        base: f64,
+        threshold: f64,
+    },
+    /// User-defined monotonic transform and its inverse
+    Custom {
+        forward: fn(f64) -> f64,
+        inverse: fn(f64) -> f64,
+    },
+}
+
+impl Scale {
+    /// Whether the parameters describe a usable scale: log bases above 1 and
+    /// a positive symlog threshold
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            Scale::Linear | Scale::Custom { .. } => true,
+            Scale::Log { base } => base.is_finite() && base > 1.0,
+            Scale::SymLog { base, threshold } => {
+                base.is_finite() && base > 1.0 && threshold.is_finite() && threshold > 0.0
+            }
+        }
+    }
+
+    /// Maps a data value to the space where the axis is linear
+    pub fn forward(&self, value: f64) -> f64 {
+        match *self {
+            Scale::Linear => value,
+            Scale::Log { base } => value.log(base),
+            Scale::SymLog { base, threshold } => {
+                if value.abs() <= threshold {
+                    value / threshold
+                } else {
+                    value.signum() * (1.0 + (value.abs() / threshold).log(base))
+                }
+            }
+            Scale::Custom { forward, .. } => forward(value),
+        }
+    }
+
+    /// Maps a position in the linear space of the axis back to a data value
+    pub fn inverse(&self, position: f64) -> f64 {
+        match *self {
+            Scale::Linear => position,
+            Scale::Log { base } => base.powf(position),
+            Scale::SymLog { base, threshold } => {
+                if position.abs() <= 1.0 {
+                    position * threshold
+                } else {
+                    position.signum() * threshold * base.powf(position.abs() - 1.0)
+                }
+            }
+            Scale::Custom { inverse, .. } => inverse(position),
+        }
+    }
+}
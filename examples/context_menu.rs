@@ -0,0 +1,416 @@
+//! Context Menu Example
+//!
+//! This example demonstrates how to add a right-click context menu to a
+//! `FigureView`, with actions to pull data out of the plot and to reset it:
+//!
+//! - "Copy image" and "Save as PNG/SVG" are shown disabled, and report that
+//!   they are not supported yet when chosen,
+//! - "Copy data as CSV" puts the visible points of every series on the
+//!   clipboard, one `series,x,y` row per point,
+//! - "Reset zoom" restores the original bounds after zooming with the wheel,
+//! - "Toggle grid" switches the `GridModel` of the axes on and off.
+//!
+//! The series are plotted once into the `AxesModel`, and all actions read and
+//! write its live state: zooming updates its bounds, the grid toggle replaces
+//! it with a new grid and plots the series again, and the CSV holds the
+//! points within the current bounds. The image actions stay disabled until
+//! `gpui-plot` can render a figure offscreen.
+//!
+//! The menu opens at the cursor, moved back inside the figure when it would
+//! overflow it, and closes when an action is chosen or on a left click
+//! elsewhere.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{bounds_tracker, ElementBounds, FigureArea, TITLE_HEIGHT};
+use gpui::{
+    div, hsla, prelude::*, px, size, App, Application, Bounds, ClipboardItem, Entity, Hsla,
+    MouseButton, MouseDownEvent, Pixels, Point, ScrollWheelEvent, Window, WindowBounds,
+    WindowOptions,
+};
+use gpui_plot::figure::axes::AxesModel;
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, Line};
+use parking_lot::RwLock;
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+/// Width of the menu in pixels
+const MENU_WIDTH: f32 = 160.0;
+/// Height of a menu entry in pixels
+const ITEM_HEIGHT: f32 = 24.0;
+/// Vertical padding of the menu in pixels, above and below its entries
+const MENU_PADDING: f32 = 4.0;
+
+/// Main application view containing the plot and its context menu
+struct ContextMenuView {
+    axes_model: Arc<RwLock<AxesModel<f64, f64>>>,
+    figure: Entity<FigureView>,
+    series: Vec<Series>,
+    home: Viewport,
+    viewport: Viewport,
+    grid: bool,
+    /// Position of the open menu, relative to the figure element
+    menu: Option<Point<Pixels>>,
+    /// Outcome of the last action
+    status: String,
+    /// Bounds of the figure element, updated on every paint
+    element_bounds: ElementBounds,
+}
+
+impl ContextMenuView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Context Menu - right-click the plot".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        let home = Viewport {
+            x: (0.0, 2.0 * PI),
+            y: (-1.5, 1.5),
+        };
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(home.bounds(), grid_model(true))));
+
+        let samples = |f: fn(f64) -> f64| -> Vec<(f64, f64)> {
+            (0..=200)
+                .map(|i| {
+                    let x = 2.0 * PI * i as f64 / 200.0;
+                    (x, f(x))
+                })
+                .collect()
+        };
+        let series = vec![
+            Series::new("sin", samples(f64::sin), Hsla::blue()),
+            Series::new("cos", samples(f64::cos), Hsla::red()),
+        ];
+
+        // Plot the series once, later changes go through the axes model
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |axes| {
+                for series in &series {
+                    axes.plot(series.line());
+                }
+            });
+        });
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        Self {
+            axes_model,
+            figure,
+            series,
+            home,
+            viewport: home,
+            grid: true,
+            menu: None,
+            status: "Right-click the plot for actions, scroll to zoom".to_string(),
+            element_bounds: ElementBounds::default(),
+        }
+    }
+
+    /// Replaces the axes model to change its grid and plots the series again
+    fn update_grid(&mut self) {
+        let mut axes = self.axes_model.write();
+        *axes = AxesModel::new(self.viewport.bounds(), grid_model(self.grid));
+        for series in &self.series {
+            axes.plot(series.line());
+        }
+    }
+
+    fn on_scroll(
+        &mut self,
+        event: &ScrollWheelEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(area) = FigureArea::new(&self.element_bounds) else {
+            return;
+        };
+        let (fx, fy) = area.fraction(event.position);
+
+        let delta = f32::from(event.delta.pixel_delta(px(16.0)).y) as f64;
+        let factor = (-delta * 0.005).exp();
+        let viewport = Viewport {
+            x: zoom(self.viewport.x, fx, factor),
+            y: zoom(self.viewport.y, fy, factor),
+        };
+        if viewport.is_valid() {
+            self.viewport = viewport;
+            self.axes_model.write().bounds = viewport.bounds();
+            cx.notify();
+        }
+    }
+
+    /// Opens the menu at the cursor, kept within the figure
+    fn on_right_mouse_down(
+        &mut self,
+        event: &MouseDownEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(area) = FigureArea::new(&self.element_bounds) else {
+            return;
+        };
+        let (x, y) = area.local(event.position);
+        let height = MenuAction::ALL.len() as f32 * ITEM_HEIGHT + 2.0 * MENU_PADDING;
+        let x = x.min(area.width() - MENU_WIDTH).max(0.0);
+        let y = y.min(TITLE_HEIGHT + area.height() - height).max(0.0);
+        self.menu = Some(Point { x: px(x), y: px(y) });
+        cx.notify();
+    }
+
+    /// Closes the menu on a click outside of it
+    fn on_left_mouse_down(
+        &mut self,
+        _event: &MouseDownEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.menu.take().is_some() {
+            cx.notify();
+        }
+    }
+
+    fn perform(&mut self, action: MenuAction, cx: &mut Context<Self>) {
+        self.menu = None;
+        self.status = match action {
+            MenuAction::CopyImage | MenuAction::SaveImage => {
+                format!("{} is not supported yet", action.label())
+            }
+            MenuAction::CopyCsv => {
+                let csv = self.csv();
+                let rows = csv.lines().count() - 1;
+                cx.write_to_clipboard(ClipboardItem::new_string(csv));
+                format!("Copied {rows} rows as CSV")
+            }
+            MenuAction::ResetZoom => {
+                self.viewport = self.home;
+                self.axes_model.write().bounds = self.home.bounds();
+                "Zoom reset".to_string()
+            }
+            MenuAction::ToggleGrid => {
+                self.grid = !self.grid;
+                self.update_grid();
+                format!("Grid {}", if self.grid { "on" } else { "off" })
+            }
+        };
+        cx.notify();
+    }
+
+    /// Points of every series within the visible bounds
+    fn csv(&self) -> String {
+        let mut csv = String::from("series,x,y\n");
+        for series in &self.series {
+            for (x, y) in self.viewport.visible(&series.points) {
+                csv.push_str(&format!("{},{x},{y}\n", series.label));
+            }
+        }
+        csv
+    }
+
+    fn render_menu(&self, cx: &mut Context<Self>) -> Option<impl IntoElement> {
+        let position = self.menu?;
+        let items = MenuAction::ALL
+            .into_iter()
+            .enumerate()
+            .map(|(index, action)| {
+                // Unsupported entries are grayed out and only update the status
+                let color = if action.is_supported() {
+                    Hsla::black()
+                } else {
+                    hsla(0.0, 0.0, 0.6, 1.0)
+                };
+                div()
+                    .id(("menu", index))
+                    .h(px(ITEM_HEIGHT))
+                    .px_2()
+                    .flex()
+                    .items_center()
+                    .text_color(color)
+                    .cursor_pointer()
+                    // Handled on mouse down, before the click closes the menu
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |this, _: &MouseDownEvent, _window, cx| {
+                            cx.stop_propagation();
+                            this.perform(action, cx);
+                        }),
+                    )
+                    .child(action.label())
+            });
+        Some(
+            div()
+                .absolute()
+                .left(position.x)
+                .top(position.y)
+                .w(px(MENU_WIDTH))
+                .flex()
+                .flex_col()
+                .py(px(MENU_PADDING))
+                .border_1()
+                .border_color(Hsla::black())
+                .bg(gpui::white())
+                .text_sm()
+                .children(items),
+        )
+    }
+}
+
+impl Render for ContextMenuView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Record the bounds of the figure element to place the menu
+        let tracker = bounds_tracker(&self.element_bounds, cx.entity_id());
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .on_mouse_down(MouseButton::Left, cx.listener(Self::on_left_mouse_down))
+            .child(div().p_2().child(self.status.clone()))
+            .child(
+                div()
+                    .relative()
+                    .size_full()
+                    .on_scroll_wheel(cx.listener(Self::on_scroll))
+                    .on_mouse_down(MouseButton::Right, cx.listener(Self::on_right_mouse_down))
+                    .child(self.figure.clone())
+                    .child(tracker)
+                    .children(self.render_menu(cx)),
+            )
+    }
+}
+
+/// Entries of the context menu
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MenuAction {
+    CopyImage,
+    SaveImage,
+    CopyCsv,
+    ResetZoom,
+    ToggleGrid,
+}
+
+impl MenuAction {
+    const ALL: [Self; 5] = [
+        Self::CopyImage,
+        Self::SaveImage,
+        Self::CopyCsv,
+        Self::ResetZoom,
+        Self::ToggleGrid,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::CopyImage => "Copy image",
+            Self::SaveImage => "Save as PNG/SVG",
+            Self::CopyCsv => "Copy data as CSV",
+            Self::ResetZoom => "Reset zoom",
+            Self::ToggleGrid => "Toggle grid",
+        }
+    }
+
+    /// Image actions need `gpui-plot` to render a figure offscreen
+    fn is_supported(self) -> bool {
+        !matches!(self, Self::CopyImage | Self::SaveImage)
+    }
+}
+
+/// A named polyline with its color
+#[derive(Clone, Debug)]
+struct Series {
+    label: String,
+    points: Vec<(f64, f64)>,
+    color: Hsla,
+}
+
+impl Series {
+    fn new(label: &str, points: Vec<(f64, f64)>, color: Hsla) -> Self {
+        Self {
+            label: label.to_string(),
+            points,
+            color,
+        }
+    }
+
+    fn line(&self) -> Line {
+        let mut line = Line::new().color(self.color);
+        for &(x, y) in &self.points {
+            line.add_point(point2(x, y));
+        }
+        line
+    }
+}
+
+/// Visible data ranges of both axes
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Viewport {
+    x: (f64, f64),
+    y: (f64, f64),
+}
+
+impl Viewport {
+    fn bounds(&self) -> AxesBounds<f64, f64> {
+        AxesBounds::new(
+            AxisRange::new(self.x.0, self.x.1),
+            AxisRange::new(self.y.0, self.y.1),
+        )
+    }
+
+    /// Rejects degenerate ranges, e.g. after zooming in too far
+    fn is_valid(&self) -> bool {
+        let valid = |(min, max): (f64, f64)| {
+            min.is_finite() && max.is_finite() && max - min > f64::EPSILON * min.abs().max(1.0)
+        };
+        valid(self.x) && valid(self.y)
+    }
+
+    /// Points within the visible x and y ranges
+    fn visible<'a>(&self, points: &'a [(f64, f64)]) -> impl Iterator<Item = (f64, f64)> + 'a {
+        let (x, y) = (self.x, self.y);
+        points
+            .iter()
+            .copied()
+            .filter(move |p| (x.0..=x.1).contains(&p.0) && (y.0..=y.1).contains(&p.1))
+    }
+}
+
+/// Grid of the axes, reduced to its outer frame when turned off
+fn grid_model(visible: bool) -> GridModel {
+    if visible {
+        GridModel::from_numbers(10, 8)
+    } else {
+        GridModel::from_numbers(1, 1)
+    }
+}
+
+/// Scales a range by `factor` around the point at `fraction` of the range
+fn zoom((min, max): (f64, f64), fraction: f64, factor: f64) -> (f64, f64) {
+    let center = min + (max - min) * fraction;
+    (
+        center - (center - min) * factor,
+        center + (max - center) * factor,
+    )
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our plot
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| ContextMenuView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
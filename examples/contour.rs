@@ -0,0 +1,506 @@
+//! Contour Example
+//!
+//! This example demonstrates how to draw iso-lines of a 2D scalar field
+//! sampled on a regular grid. `ScalarField::contour` runs marching squares
+//! for a level, resolving saddle cells from the value at their center, and
+//! joins the resulting segments into polylines. A `Contour` geometry draws
+//! the lines of several levels, each with its own color.
+//!
+//! Filled contours are drawn by `ContourBands`, which splits each grid cell
+//! into two triangles and clips them between consecutive levels, the field
+//! being linear over a triangle. The pieces of each band are painted over the
+//! figure as one translucent polygon with the shared `fill_layer`. Level
+//! labels are placed inline on the longest line of each level with gpui
+//! elements, through the shared `FigureArea`.
+//!
+//! The field is the directivity of a 20 cm line source, in dB, against the
+//! angle and the frequency.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{bounds_tracker, fill_layer, ElementBounds, FigureArea, Polygon};
+use gpui::{
+    div, hsla, prelude::*, px, size, App, Application, Bounds, ClickEvent, Entity, Hsla, Window,
+    WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::{AxesContext, AxesModel};
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, GeometryAxes, Line};
+use parking_lot::RwLock;
+use std::collections::{HashMap, HashSet};
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+/// Angles in degrees
+const X_RANGE: (f64, f64) = (-90.0, 90.0);
+/// Frequencies in kHz
+const Y_RANGE: (f64, f64) = (0.2, 10.0);
+
+/// Main application view containing the contour plot
+struct ContourView {
+    model: Arc<RwLock<FigureModel>>,
+    axes_model: Arc<RwLock<AxesModel<f64, f64>>>,
+    figure: Entity<FigureView>,
+    field: Arc<ScalarField>,
+    levels: Vec<f64>,
+    filled: bool,
+    labels: bool,
+    /// Bounds of the figure element, updated on every paint
+    element_bounds: ElementBounds,
+}
+
+impl ContourView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Contour - directivity of a line source (dB)".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        let x_range = AxisRange::new(X_RANGE.0, X_RANGE.1);
+        let y_range = AxisRange::new(Y_RANGE.0, Y_RANGE.1);
+        let axes_bounds = AxesBounds::new(x_range, y_range);
+
+        let grid = GridModel::from_numbers(6, 10);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(axes_bounds, grid)));
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        let field = Arc::new(ScalarField::sample(X_RANGE, Y_RANGE, 120, 80, directivity));
+
+        Self {
+            model,
+            axes_model,
+            figure,
+            field,
+            levels: vec![-30.0, -20.0, -10.0, -6.0, -3.0],
+            filled: true,
+            labels: true,
+            element_bounds: ElementBounds::default(),
+        }
+    }
+
+    /// Level labels at the middle of the longest line of each level
+    fn render_labels(&self, contour: &Contour) -> Option<impl IntoElement> {
+        let area = FigureArea::new(&self.element_bounds)?;
+        let labels = contour.lines.iter().filter_map(|(level, lines)| {
+            let line = lines.iter().max_by_key(|line| line.len())?;
+            let (x, y) = *line.get(line.len() / 2)?;
+            let (left, top) = area.data_offset((x, y), X_RANGE, Y_RANGE);
+            Some(
+                div()
+                    .absolute()
+                    .left(px(left - 12.0))
+                    .top(px(top - 8.0))
+                    .px_1()
+                    .bg(gpui::white())
+                    .text_sm()
+                    .text_color(level_color(&self.levels, *level))
+                    .child(format!("{level}")),
+            )
+        });
+        Some(div().absolute().size_full().children(labels))
+    }
+
+    fn render_toggle(
+        &self,
+        id: &'static str,
+        label: String,
+        cx: &mut Context<Self>,
+        action: fn(&mut Self),
+    ) -> impl IntoElement {
+        div()
+            .id(id)
+            .px_2()
+            .border_1()
+            .border_color(Hsla::black())
+            .cursor_pointer()
+            .on_click(cx.listener(move |this, _: &ClickEvent, _window, cx| {
+                action(this);
+                cx.notify();
+            }))
+            .child(label)
+    }
+}
+
+impl Render for ContourView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Rebuilt when the display options change
+        let contour = Contour::new(&self.field, &self.levels);
+        let mut model = self.model.write();
+        model.clear_plots();
+        model.add_plot_with(|plot| {
+            plot.add_axes_with(self.axes_model.clone(), |axes| {
+                axes.clear_elements();
+                axes.plot(contour.clone());
+            });
+        });
+        let bands = if self.filled {
+            ContourBands::new(self.field.clone(), self.levels.clone()).polygons()
+        } else {
+            Vec::new()
+        };
+
+        let toggles = [
+            self.render_toggle("filled", format!("Filled: {}", self.filled), cx, |this| {
+                this.filled = !this.filled
+            }),
+            self.render_toggle("labels", format!("Labels: {}", self.labels), cx, |this| {
+                this.labels = !this.labels
+            }),
+        ];
+        let labels = if self.labels {
+            self.render_labels(&contour)
+        } else {
+            None
+        };
+
+        // Record the bounds of the figure element to place the labels
+        let tracker = bounds_tracker(&self.element_bounds, cx.entity_id());
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(div().flex().flex_row().gap_2().p_2().children(toggles))
+            .child(
+                div()
+                    .relative()
+                    .size_full()
+                    .child(self.figure.clone())
+                    .child(fill_layer(bands, X_RANGE, Y_RANGE))
+                    .child(tracker)
+                    .children(labels),
+            )
+    }
+}
+
+/// Crossing of an iso-line with an edge of the grid, identified by the
+/// column and row of its lower or left end
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Edge {
+    Horizontal(usize, usize),
+    Vertical(usize, usize),
+}
+
+/// Points of an iso-line
+type Polyline = Vec<(f64, f64)>;
+
+/// Values sampled on a regular grid, `values[row][column]` with row 0 at the
+/// bottom
+struct ScalarField {
+    values: Vec<Vec<f64>>,
+    x: (f64, f64),
+    y: (f64, f64),
+}
+
+impl ScalarField {
+    /// Samples `f(x, y)` on `columns` by `rows` points covering the ranges
+    fn sample(
+        x: (f64, f64),
+        y: (f64, f64),
+        columns: usize,
+        rows: usize,
+        f: impl Fn(f64, f64) -> f64,
+    ) -> Self {
+        let mut field = Self {
+            values: Vec::new(),
+            x,
+            y,
+        };
+        field.values = (0..rows)
+            .map(|row| {
+                (0..columns)
+                    .map(|column| {
+                        let point = field.position(column as f64, row as f64, columns, rows);
+                        f(point.0, point.1)
+                    })
+                    .collect()
+            })
+            .collect();
+        field
+    }
+
+    fn size(&self) -> (usize, usize) {
+        (self.values.first().map_or(0, Vec::len), self.values.len())
+    }
+
+    /// Data coordinates of a fractional grid position
+    fn position(&self, column: f64, row: f64, columns: usize, rows: usize) -> (f64, f64) {
+        let fx = column / (columns.max(2) - 1) as f64;
+        let fy = row / (rows.max(2) - 1) as f64;
+        (
+            self.x.0 + (self.x.1 - self.x.0) * fx,
+            self.y.0 + (self.y.1 - self.y.0) * fy,
+        )
+    }
+
+    /// Point where the iso-line of `level` crosses `edge`
+    fn crossing(&self, edge: Edge, level: f64) -> (f64, f64) {
+        let (columns, rows) = self.size();
+        let (column, row, end) = match edge {
+            Edge::Horizontal(column, row) => (column, row, self.values[row][column + 1]),
+            Edge::Vertical(column, row) => (column, row, self.values[row + 1][column]),
+        };
+        let start = self.values[row][column];
+        let t = ((level - start) / (end - start)).clamp(0.0, 1.0);
+        let (column, row) = match edge {
+            Edge::Horizontal(..) => (column as f64 + t, row as f64),
+            Edge::Vertical(..) => (column as f64, row as f64 + t),
+        };
+        self.position(column, row, columns, rows)
+    }
+
+    /// Iso-lines of `level` as polylines, closed lines ending on their start
+    fn contour(&self, level: f64) -> Vec<Polyline> {
+        let (columns, rows) = self.size();
+        let mut neighbours: HashMap<Edge, Vec<Edge>> = HashMap::new();
+        for row in 0..rows.saturating_sub(1) {
+            for column in 0..columns.saturating_sub(1) {
+                let corners = [
+                    self.values[row][column],
+                    self.values[row][column + 1],
+                    self.values[row + 1][column + 1],
+                    self.values[row + 1][column],
+                ];
+                let above = corners.map(|value| value >= level);
+                let bottom = Edge::Horizontal(column, row);
+                let right = Edge::Vertical(column + 1, row);
+                let top = Edge::Horizontal(column, row + 1);
+                let left = Edge::Vertical(column, row);
+                let crossed: Vec<Edge> = [
+                    (bottom, above[0] != above[1]),
+                    (right, above[1] != above[2]),
+                    (top, above[2] != above[3]),
+                    (left, above[3] != above[0]),
+                ]
+                .into_iter()
+                .filter_map(|(edge, crossed)| crossed.then_some(edge))
+                .collect();
+
+                let segments = match crossed.len() {
+                    2 => vec![(crossed[0], crossed[1])],
+                    // Saddle: the center tells which opposite corners connect
+                    4 => {
+                        let center = corners.iter().sum::<f64>() / 4.0;
+                        if (center >= level) == above[0] {
+                            vec![(bottom, right), (top, left)]
+                        } else {
+                            vec![(left, bottom), (right, top)]
+                        }
+                    }
+                    _ => Vec::new(),
+                };
+                for (a, b) in segments {
+                    neighbours.entry(a).or_default().push(b);
+                    neighbours.entry(b).or_default().push(a);
+                }
+            }
+        }
+
+        // Open lines start at an edge of the grid, then closed lines remain
+        let mut starts: Vec<Edge> = neighbours
+            .iter()
+            .filter(|(_, next)| next.len() == 1)
+            .map(|(edge, _)| *edge)
+            .collect();
+        starts.extend(neighbours.keys().copied());
+
+        let mut visited = HashSet::new();
+        let mut lines = Vec::new();
+        for start in starts {
+            if visited.contains(&start) {
+                continue;
+            }
+            let mut line = Vec::new();
+            let mut current = start;
+            loop {
+                visited.insert(current);
+                line.push(self.crossing(current, level));
+                let next = neighbours[&current]
+                    .iter()
+                    .find(|edge| !visited.contains(*edge));
+                match next {
+                    Some(&next) => current = next,
+                    None => break,
+                }
+            }
+            if line.len() > 2 && neighbours[&current].contains(&start) {
+                line.push(line[0]);
+            }
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+/// Iso-lines of several levels
+#[derive(Clone)]
+struct Contour {
+    lines: Vec<(f64, Vec<Polyline>)>,
+    colors: Vec<Hsla>,
+}
+
+impl Contour {
+    fn new(field: &ScalarField, levels: &[f64]) -> Self {
+        Self {
+            lines: levels
+                .iter()
+                .map(|&level| (level, field.contour(level)))
+                .collect(),
+            colors: levels
+                .iter()
+                .map(|&level| level_color(levels, level))
+                .collect(),
+        }
+    }
+}
+
+impl GeometryAxes for Contour {
+    type X = f64;
+    type Y = f64;
+
+    fn render_axes(&mut self, cx: &mut AxesContext<Self::X, Self::Y>) {
+        for ((_, lines), color) in self.lines.iter().zip(&self.colors) {
+            for points in lines {
+                let mut line = Line::new().color(*color);
+                for &(x, y) in points {
+                    line.add_point(point2(x, y));
+                }
+                line.render_axes(cx);
+            }
+        }
+    }
+}
+
+/// Bands between consecutive levels, filled in translucent colors
+#[derive(Clone)]
+struct ContourBands {
+    field: Arc<ScalarField>,
+    /// Increasing levels, the first band is below the first level and the
+    /// last one above the last level
+    levels: Vec<f64>,
+}
+
+impl ContourBands {
+    fn new(field: Arc<ScalarField>, levels: Vec<f64>) -> Self {
+        Self { field, levels }
+    }
+
+    fn band(&self, value: f64) -> usize {
+        self.levels.partition_point(|&level| level <= value)
+    }
+
+    fn band_color(&self, band: usize) -> Hsla {
+        let t = band as f32 / self.levels.len().max(1) as f32;
+        hsla(0.66 * (1.0 - t), 0.8, 0.5, 0.3)
+    }
+
+    /// One polygon per band, made of the cells within the band and of the
+    /// pieces of the triangles of the other cells
+    fn polygons(&self) -> Vec<Polygon> {
+        let field = &self.field;
+        let (columns, rows) = field.size();
+        let mut bands: Vec<Vec<Vec<(f64, f64)>>> = vec![Vec::new(); self.levels.len() + 1];
+        for row in 0..rows.saturating_sub(1) {
+            for column in 0..columns.saturating_sub(1) {
+                // Corners counterclockwise from the bottom left, with their value
+                let corners = [(0, 0), (1, 0), (1, 1), (0, 1)].map(|(dc, dr)| {
+                    let (c, r) = (column + dc, row + dr);
+                    let (x, y) = field.position(c as f64, r as f64, columns, rows);
+                    (x, y, field.values[r][c])
+                });
+                let band = self.band(corners[0].2);
+                if corners.iter().all(|corner| self.band(corner.2) == band) {
+                    bands[band].push(corners.iter().map(|&(x, y, _)| (x, y)).collect());
+                    continue;
+                }
+                for triangle in [
+                    [corners[0], corners[1], corners[2]],
+                    [corners[0], corners[2], corners[3]],
+                ] {
+                    let lowest = triangle.iter().map(|c| self.band(c.2)).min().unwrap_or(0);
+                    let highest = triangle.iter().map(|c| self.band(c.2)).max().unwrap_or(0);
+                    let pieces = (lowest..=highest)
+                        .filter_map(|band| Some((band, self.piece(&triangle, band)?)));
+                    for (band, piece) in pieces {
+                        bands[band].push(piece);
+                    }
+                }
+            }
+        }
+        bands
+            .into_iter()
+            .enumerate()
+            .map(|(band, rings)| Polygon::with_rings(rings, self.band_color(band)))
+            .collect()
+    }
+
+    /// Part of a triangle within `band`, `None` when it does not reach it
+    fn piece(&self, triangle: &[(f64, f64, f64)], band: usize) -> Option<Vec<(f64, f64)>> {
+        let mut piece = triangle.to_vec();
+        if band > 0 {
+            piece = clip(&piece, self.levels[band - 1], true);
+        }
+        if let Some(&level) = self.levels.get(band) {
+            piece = clip(&piece, level, false);
+        }
+        (piece.len() >= 3).then(|| piece.iter().map(|&(x, y, _)| (x, y)).collect())
+    }
+}
+
+/// Part of a convex polygon with a value at each vertex where the value is at
+/// least `level` when `above`, or below `level` otherwise, the value being
+/// linear along the edges
+fn clip(polygon: &[(f64, f64, f64)], level: f64, above: bool) -> Vec<(f64, f64, f64)> {
+    let inside = |value: f64| (value >= level) == above;
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        if inside(a.2) {
+            clipped.push(a);
+        }
+        if inside(a.2) != inside(b.2) {
+            let t = (level - a.2) / (b.2 - a.2);
+            clipped.push((a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t, level));
+        }
+    }
+    clipped
+}
+
+/// Color of `level`, from blue for the lowest to red for the highest
+fn level_color(levels: &[f64], level: f64) -> Hsla {
+    let index = levels.iter().position(|&l| l == level).unwrap_or(0);
+    let t = index as f32 / (levels.len().max(2) - 1) as f32;
+    hsla(0.66 * (1.0 - t), 0.8, 0.4, 1.0)
+}
+
+/// Level in dB of a 20 cm line source at `angle` degrees and `frequency` kHz
+fn directivity(angle: f64, frequency: f64) -> f64 {
+    let wavenumber = 2.0 * PI * frequency * 1000.0 / 343.0;
+    let u = wavenumber * 0.2 / 2.0 * angle.to_radians().sin();
+    let response = if u.abs() < 1e-9 { 1.0 } else { u.sin() / u };
+    (20.0 * response.abs().log10()).max(-40.0)
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our contour plot
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| ContourView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
@@ -0,0 +1,274 @@
+//! Error Bars Example
+//!
+//! This example demonstrates how to show the uncertainty of measurements with
+//! an `ErrorBar` geometry implementing `GeometryAxes`. Each point can have an
+//! error along y, along x, or both, either symmetric (e.g. mean ± standard
+//! deviation) or asymmetric (e.g. median with its 10th and 90th percentiles),
+//! drawn as segments ending with caps.
+//!
+//! Error bars are painted over the figure with `paint_layer` from the
+//! examples' common module, on top of a `Line` through the means plotted in
+//! the axes, so they combine with line and scatter series. The segments span
+//! the errors in data units, while the caps and the square markers are sized
+//! in pixels like the markers of the scatter example, so they keep their size
+//! whatever the axes ranges and the window size are.
+//!
+//! The data is a benchmark run ten times for each of several input sizes.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{fill_path, paint_layer, stroke_path, Stroke};
+use gpui::{
+    div, point, prelude::*, px, size, App, Application, Bounds, Entity, Hsla, Pixels, Point,
+    Window, WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::AxesModel;
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, Line};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+const X_MIN: f64 = 0.0;
+const X_MAX: f64 = 11.0;
+const Y_MIN: f64 = 0.0;
+const Y_MAX: f64 = 60.0;
+
+/// Main application view containing the benchmark plot
+struct ErrorBarsView {
+    figure: Entity<FigureView>,
+    errors: Vec<ErrorBar>,
+}
+
+impl ErrorBarsView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Error Bars - benchmark time per input size".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        let x_range = AxisRange::new(X_MIN, X_MAX);
+        let y_range = AxisRange::new(Y_MIN, Y_MAX);
+        let axes_bounds = AxesBounds::new(x_range, y_range);
+
+        let grid = GridModel::from_numbers(11, 6);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(axes_bounds, grid)));
+
+        // The axes hold the grid and the means, error bars are painted over
+        // the figure
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |_axes| {});
+        });
+
+        // Mean ± standard deviation of ten runs
+        let baseline = Hsla::blue();
+        let mut baseline_errors = ErrorBar::new(baseline);
+        let mut means = Line::new().color(baseline);
+        for input in 1..=10 {
+            let runs = runs(input, 0);
+            let (mean, deviation) = mean_deviation(&runs);
+            baseline_errors.add_point(input as f64, mean, Error::Symmetric(deviation));
+            means.add_point(point2(input as f64, mean));
+        }
+        axes_model.write().plot(means);
+
+        // Median with the 10th and 90th percentiles, slightly shifted along x
+        // and with the spread of the input sizes
+        let mut candidate_errors = ErrorBar::new(Hsla::red());
+        for input in 1..=10 {
+            let mut runs = runs(input, 1);
+            runs.sort_by(f64::total_cmp);
+            let median = quantile(&runs, 0.5);
+            candidate_errors.add_point_with(
+                input as f64 + 0.2,
+                median,
+                Some(Error::Symmetric(0.1)),
+                Some(Error::Asymmetric {
+                    minus: median - quantile(&runs, 0.1),
+                    plus: quantile(&runs, 0.9) - median,
+                }),
+            );
+        }
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        Self {
+            figure,
+            errors: vec![baseline_errors, candidate_errors],
+        }
+    }
+}
+
+impl Render for ErrorBarsView {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        // Error bars are painted over the figure, caps and markers sized in
+        // pixels
+        let errors = self.errors.clone();
+        let bars = paint_layer((X_MIN, X_MAX), (Y_MIN, Y_MAX), move |window, to_pixels| {
+            for error in &errors {
+                error.paint(window, to_pixels);
+            }
+        });
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(
+                div()
+                    .relative()
+                    .size_full()
+                    .child(self.figure.clone())
+                    .child(bars),
+            )
+    }
+}
+
+/// Extent of an error around a value
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Error {
+    /// Same distance below and above
+    Symmetric(f64),
+    /// Distances below and above, both positive
+    Asymmetric { minus: f64, plus: f64 },
+}
+
+impl Error {
+    /// Range covered around `value`
+    fn range(self, value: f64) -> (f64, f64) {
+        match self {
+            Error::Symmetric(error) => (value - error, value + error),
+            Error::Asymmetric { minus, plus } => (value - minus, value + plus),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ErrorPoint {
+    x: f64,
+    y: f64,
+    x_error: Option<Error>,
+    y_error: Option<Error>,
+}
+
+/// Error bars with caps around points marked with squares
+#[derive(Clone)]
+struct ErrorBar {
+    points: Vec<ErrorPoint>,
+    color: Hsla,
+    /// Half length of the caps, in pixels
+    cap: f32,
+    /// Side of the square markers in pixels, none when 0
+    marker: f32,
+}
+
+impl ErrorBar {
+    fn new(color: Hsla) -> Self {
+        Self {
+            points: Vec::new(),
+            color,
+            cap: 4.0,
+            marker: 6.0,
+        }
+    }
+
+    /// Adds a point with an error along y
+    fn add_point(&mut self, x: f64, y: f64, y_error: Error) {
+        self.add_point_with(x, y, None, Some(y_error));
+    }
+
+    /// Adds a point with optional errors along x and y
+    fn add_point_with(&mut self, x: f64, y: f64, x_error: Option<Error>, y_error: Option<Error>) {
+        self.points.push(ErrorPoint {
+            x,
+            y,
+            x_error,
+            y_error,
+        });
+    }
+
+    /// Paints the bars, caps and markers, `to_pixels` mapping data
+    /// coordinates to window pixels
+    fn paint(&self, window: &mut Window, to_pixels: &dyn Fn((f64, f64)) -> Point<Pixels>) {
+        let style = Stroke::new(Vec::new(), self.color);
+        let cap = px(self.cap);
+        for sample in &self.points {
+            if let Some(error) = sample.y_error {
+                let (low, high) = error.range(sample.y);
+                let (low, high) = (to_pixels((sample.x, low)), to_pixels((sample.x, high)));
+                stroke_path(window, &[low, high], &style);
+                for end in [low, high] {
+                    let cap = [point(end.x - cap, end.y), point(end.x + cap, end.y)];
+                    stroke_path(window, &cap, &style);
+                }
+            }
+            if let Some(error) = sample.x_error {
+                let (low, high) = error.range(sample.x);
+                let (low, high) = (to_pixels((low, sample.y)), to_pixels((high, sample.y)));
+                stroke_path(window, &[low, high], &style);
+                for end in [low, high] {
+                    let cap = [point(end.x, end.y - cap), point(end.x, end.y + cap)];
+                    stroke_path(window, &cap, &style);
+                }
+            }
+
+            if self.marker > 0.0 {
+                let center = to_pixels((sample.x, sample.y));
+                let half = self.marker / 2.0;
+                let square = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+                    .map(|(dx, dy)| point(center.x + px(dx * half), center.y + px(dy * half)));
+                fill_path(window, [square.to_vec()], self.color);
+            }
+        }
+    }
+}
+
+/// Deterministic timings of ten runs, in milliseconds
+fn runs(input: usize, variant: usize) -> Vec<f64> {
+    let expected = 4.0 * input as f64 + 5.0 * variant as f64;
+    (0..10)
+        .map(|run| {
+            let seed = (input * 10 + run) * 3 + variant;
+            let noise = ((seed as f64 * 12.9898).sin() * 43758.5453).fract();
+            expected * (1.0 + 0.15 * noise)
+        })
+        .collect()
+}
+
+/// Quantile `q` of sorted values, interpolating linearly between the two
+/// closest ranks
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let rank = q.clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+    let (below, above) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[below] + (sorted[above] - sorted[below]) * rank.fract()
+}
+
+/// Mean and sample standard deviation
+fn mean_deviation(values: &[f64]) -> (f64, f64) {
+    let count = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / count;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (count - 1.0).max(1.0);
+    (mean, variance.sqrt())
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our benchmark plot
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| ErrorBarsView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
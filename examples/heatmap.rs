@@ -0,0 +1,513 @@
+//! Heatmap Example
+//!
+//! This example demonstrates how to draw a 2D grid of values with a `Heatmap`.
+//! Each value is normalized between `vmin` and `vmax`, linearly or
+//! logarithmically, and mapped to a color by a `Colormap`: viridis, magma,
+//! inferno, plasma, coolwarm, grayscale, or a custom list of colors. Cells are
+//! filled rectangles painted over the figure with the shared `fill_layer`.
+//!
+//! A `Colorbar` built from the heatmap shows the colormap and its limits next
+//! to the figure, so both always use the same normalization. Two datasets are
+//! provided: a synthetic spectrogram of a chirp, on a logarithmic scale, and
+//! the correlation matrix of a few variables, on a diverging colormap.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{fill_layer, Polygon};
+use gpui::{
+    div, prelude::*, px, rgb, size, App, Application, Bounds, ClickEvent, Entity, Hsla, Rgba,
+    Window, WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::AxesModel;
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{AxesBounds, AxisRange};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+const VIRIDIS: [u32; 10] = [
+    0x440154, 0x482878, 0x3e4989, 0x31688e, 0x26828e, 0x1f9e89, 0x35b779, 0x6ece58, 0xb5de2b,
+    0xfde725,
+];
+const MAGMA: [u32; 10] = [
+    0x000004, 0x180f3d, 0x440f76, 0x721f81, 0x9e2f7f, 0xcd4071, 0xf1605d, 0xfd9668, 0xfeca8d,
+    0xfcfdbf,
+];
+const INFERNO: [u32; 10] = [
+    0x000004, 0x1b0c41, 0x4a0c6b, 0x781c6d, 0xa52c60, 0xcf4446, 0xed6925, 0xfb9b06, 0xf7d13d,
+    0xfcffa4,
+];
+const PLASMA: [u32; 10] = [
+    0x0d0887, 0x46039f, 0x7201a8, 0x9c179e, 0xbd3786, 0xd8576b, 0xed7953, 0xfb9f3a, 0xfdca26,
+    0xf0f921,
+];
+const COOLWARM: [u32; 8] = [
+    0x3b4cc0, 0x6788ee, 0x9abbff, 0xc9d7f0, 0xedd1c2, 0xf7a889, 0xe26952, 0xb40426,
+];
+
+/// Main application view containing the heatmap and its colorbar
+struct HeatmapView {
+    axes_model: Arc<RwLock<AxesModel<f64, f64>>>,
+    figure: Entity<FigureView>,
+    dataset: Dataset,
+    heatmap: Heatmap,
+}
+
+impl HeatmapView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Heatmap - colormaps and colorbar".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        let dataset = Dataset::Spectrogram;
+        let heatmap = dataset.heatmap();
+        let grid = GridModel::from_numbers(10, 8);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(heatmap.bounds(), grid)));
+
+        // The axes only hold the grid, cells are painted over the figure
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |_axes| {});
+        });
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        Self {
+            axes_model,
+            figure,
+            dataset,
+            heatmap,
+        }
+    }
+
+    fn render_button(
+        &self,
+        id: &'static str,
+        label: String,
+        cx: &mut Context<Self>,
+        action: fn(&mut Self),
+    ) -> impl IntoElement {
+        div()
+            .id(id)
+            .px_2()
+            .border_1()
+            .border_color(Hsla::black())
+            .cursor_pointer()
+            .on_click(cx.listener(move |this, _: &ClickEvent, _window, cx| {
+                action(this);
+                this.axes_model.write().bounds = this.heatmap.bounds();
+                cx.notify();
+            }))
+            .child(label)
+    }
+}
+
+impl Render for HeatmapView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let buttons = [
+            self.render_button(
+                "dataset",
+                format!("Dataset: {:?}", self.dataset),
+                cx,
+                |this| {
+                    this.dataset = match this.dataset {
+                        Dataset::Spectrogram => Dataset::Correlation,
+                        Dataset::Correlation => Dataset::Spectrogram,
+                    };
+                    this.heatmap = this.dataset.heatmap();
+                },
+            ),
+            self.render_button(
+                "colormap",
+                format!("Colormap: {}", self.heatmap.colormap.name()),
+                cx,
+                |this| {
+                    let colormap = match this.heatmap.colormap {
+                        Colormap::Viridis => Colormap::Magma,
+                        Colormap::Magma => Colormap::Inferno,
+                        Colormap::Inferno => Colormap::Plasma,
+                        Colormap::Plasma => Colormap::Coolwarm,
+                        Colormap::Coolwarm => Colormap::Grayscale,
+                        Colormap::Grayscale => {
+                            Colormap::Custom(vec![rgb(0xffffff), rgb(0x2e7d32), rgb(0x1b1b1b)])
+                        }
+                        Colormap::Custom(_) => Colormap::Viridis,
+                    };
+                    this.heatmap.colormap = colormap;
+                },
+            ),
+            self.render_button(
+                "normalize",
+                format!("Scale: {:?}", self.heatmap.normalize),
+                cx,
+                |this| {
+                    this.heatmap.normalize = match this.heatmap.normalize {
+                        Normalize::Linear => Normalize::Log,
+                        Normalize::Log => Normalize::Linear,
+                    };
+                },
+            ),
+        ];
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(div().flex().flex_row().gap_2().p_2().children(buttons))
+            .child(
+                div()
+                    .size_full()
+                    .flex()
+                    .flex_row()
+                    .child(
+                        div()
+                            .relative()
+                            .flex_1()
+                            .child(self.figure.clone())
+                            .child(fill_layer(
+                                self.heatmap.cells(),
+                                self.heatmap.x,
+                                self.heatmap.y,
+                            )),
+                    )
+                    .child(self.heatmap.colorbar().render()),
+            )
+    }
+}
+
+/// Data shown by the example
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Dataset {
+    Spectrogram,
+    Correlation,
+}
+
+impl Dataset {
+    fn heatmap(self) -> Heatmap {
+        match self {
+            Dataset::Spectrogram => {
+                // Power of a chirp from 50 Hz to 250 Hz and its first harmonic
+                // over two seconds, above a noise floor
+                let (columns, rows) = (60, 40);
+                let values = (0..rows)
+                    .map(|row| {
+                        let frequency = 500.0 * (row as f64 + 0.5) / rows as f64;
+                        (0..columns)
+                            .map(|column| {
+                                let time = 2.0 * (column as f64 + 0.5) / columns as f64;
+                                let fundamental = 50.0 + 100.0 * time;
+                                let peak =
+                                    |f: f64, width: f64| (-((frequency - f) / width).powi(2)).exp();
+                                let seed = row * columns + column;
+                                let noise = ((seed as f64 * 12.9898).sin() * 43758.5453).fract();
+                                peak(fundamental, 15.0)
+                                    + 0.05 * peak(2.0 * fundamental, 20.0)
+                                    + 1e-3 * (1.5 + noise)
+                            })
+                            .collect()
+                    })
+                    .collect();
+                Heatmap::new(values, (0.0, 2.0), (0.0, 500.0))
+                    .colormap(Colormap::Magma)
+                    .normalize(Normalize::Log)
+            }
+            Dataset::Correlation => {
+                // Variables mixing two latent factors with different weights
+                let variables = 8;
+                let samples: Vec<Vec<f64>> = (0..variables)
+                    .map(|v| {
+                        let weight = v as f64 / (variables - 1) as f64;
+                        (0..500)
+                            .map(|i| {
+                                let latent = |k: usize| {
+                                    ((i as f64 * 7.13 + k as f64 * 3.7).sin() * 43758.5453).fract()
+                                };
+                                let noise = latent(10 + v) * 0.5;
+                                (1.0 - weight) * latent(0) - weight * latent(1) + noise
+                            })
+                            .collect()
+                    })
+                    .collect();
+                let values = samples
+                    .iter()
+                    .map(|a| samples.iter().map(|b| correlation(a, b)).collect())
+                    .collect();
+                let extent = (-0.5, variables as f64 - 0.5);
+                Heatmap::new(values, extent, extent)
+                    .colormap(Colormap::Coolwarm)
+                    .limits(-1.0, 1.0)
+            }
+        }
+    }
+}
+
+/// Maps a value between 0 and 1 to a color
+#[derive(Clone, Debug, PartialEq)]
+enum Colormap {
+    Viridis,
+    Magma,
+    Inferno,
+    Plasma,
+    /// Diverging from blue to red through light gray
+    Coolwarm,
+    Grayscale,
+    /// Colors evenly spread between 0 and 1
+    Custom(Vec<Rgba>),
+}
+
+impl Colormap {
+    fn name(&self) -> &'static str {
+        match self {
+            Colormap::Viridis => "viridis",
+            Colormap::Magma => "magma",
+            Colormap::Inferno => "inferno",
+            Colormap::Plasma => "plasma",
+            Colormap::Coolwarm => "coolwarm",
+            Colormap::Grayscale => "grayscale",
+            Colormap::Custom(_) => "custom",
+        }
+    }
+
+    fn stops(&self) -> Vec<Rgba> {
+        let hex = |colors: &[u32]| colors.iter().map(|&color| rgb(color)).collect();
+        match self {
+            Colormap::Viridis => hex(&VIRIDIS),
+            Colormap::Magma => hex(&MAGMA),
+            Colormap::Inferno => hex(&INFERNO),
+            Colormap::Plasma => hex(&PLASMA),
+            Colormap::Coolwarm => hex(&COOLWARM),
+            Colormap::Grayscale => hex(&[0x000000, 0xffffff]),
+            Colormap::Custom(colors) => colors.clone(),
+        }
+    }
+
+    /// Color at `t`, interpolated between the two nearest stops
+    fn color(&self, t: f64) -> Hsla {
+        let stops = self.stops();
+        let (Some(&first), Some(&last)) = (stops.first(), stops.last()) else {
+            return Hsla::black();
+        };
+        if stops.len() == 1 || t <= 0.0 {
+            return first.into();
+        }
+        if t >= 1.0 {
+            return last.into();
+        }
+        let position = t * (stops.len() - 1) as f64;
+        let index = position.floor() as usize;
+        let f = (position - index as f64) as f32;
+        let (a, b) = (stops[index], stops[index + 1]);
+        Rgba {
+            r: a.r + (b.r - a.r) * f,
+            g: a.g + (b.g - a.g) * f,
+            b: a.b + (b.b - a.b) * f,
+            a: a.a + (b.a - a.a) * f,
+        }
+        .into()
+    }
+}
+
+/// Mapping of values between `vmin` and `vmax` to [0, 1]
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Normalize {
+    Linear,
+    /// Ratio to `vmin` on a logarithmic scale, for positive values only
+    Log,
+}
+
+impl Normalize {
+    fn apply(self, value: f64, (vmin, vmax): (f64, f64)) -> Option<f64> {
+        let t = match self {
+            Normalize::Linear => (value - vmin) / (vmax - vmin),
+            Normalize::Log if value > 0.0 => (value / vmin).ln() / (vmax / vmin).ln(),
+            Normalize::Log => return None,
+        };
+        t.is_finite().then(|| t.clamp(0.0, 1.0))
+    }
+
+    fn inverse(self, t: f64, (vmin, vmax): (f64, f64)) -> f64 {
+        match self {
+            Normalize::Linear => vmin + (vmax - vmin) * t,
+            Normalize::Log => vmin * (vmax / vmin).powf(t),
+        }
+    }
+}
+
+/// Grid of values drawn as colored cells, with row 0 at the bottom
+#[derive(Clone)]
+struct Heatmap {
+    values: Vec<Vec<f64>>,
+    x: (f64, f64),
+    y: (f64, f64),
+    colormap: Colormap,
+    normalize: Normalize,
+    vmin: Option<f64>,
+    vmax: Option<f64>,
+}
+
+impl Heatmap {
+    /// Heatmap of `values[row][column]` covering the `x` and `y` ranges
+    fn new(values: Vec<Vec<f64>>, x: (f64, f64), y: (f64, f64)) -> Self {
+        Self {
+            values,
+            x,
+            y,
+            colormap: Colormap::Viridis,
+            normalize: Normalize::Linear,
+            vmin: None,
+            vmax: None,
+        }
+    }
+
+    fn colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = colormap;
+        self
+    }
+
+    fn normalize(mut self, normalize: Normalize) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Fixes the values mapped to both ends of the colormap
+    fn limits(mut self, vmin: f64, vmax: f64) -> Self {
+        self.vmin = Some(vmin);
+        self.vmax = Some(vmax);
+        self
+    }
+
+    /// `vmin` and `vmax`, defaulting to the range of the values, positive
+    /// values only on a logarithmic scale
+    fn range(&self) -> (f64, f64) {
+        let log = self.normalize == Normalize::Log;
+        let (min, max) = self
+            .values
+            .iter()
+            .flatten()
+            .filter(|value| value.is_finite() && (!log || **value > 0.0))
+            .fold((f64::MAX, f64::MIN), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            });
+        let vmin = self.vmin.filter(|vmin| !log || *vmin > 0.0).unwrap_or(min);
+        let vmax = self.vmax.unwrap_or(max);
+        if vmin < vmax {
+            (vmin, vmax)
+        } else {
+            (vmin, vmin + 1.0)
+        }
+    }
+
+    fn bounds(&self) -> AxesBounds<f64, f64> {
+        AxesBounds::new(
+            AxisRange::new(self.x.0, self.x.1),
+            AxisRange::new(self.y.0, self.y.1),
+        )
+    }
+
+    /// Cells as rectangles of their color. Cells without a color, e.g.
+    /// negative values on a log scale, are left empty
+    fn cells(&self) -> Vec<Polygon> {
+        let range = self.range();
+        let rows = self.values.len();
+        let height = (self.y.1 - self.y.0) / rows.max(1) as f64;
+        let mut cells = Vec::new();
+        for (row, values) in self.values.iter().enumerate() {
+            let width = (self.x.1 - self.x.0) / values.len().max(1) as f64;
+            for (column, &value) in values.iter().enumerate() {
+                let Some(t) = self.normalize.apply(value, range) else {
+                    continue;
+                };
+                let left = self.x.0 + width * column as f64;
+                let bottom = self.y.0 + height * row as f64;
+                cells.push(Polygon::rect(
+                    (left, bottom),
+                    (left + width, bottom + height),
+                    self.colormap.color(t),
+                ));
+            }
+        }
+        cells
+    }
+
+    fn colorbar(&self) -> Colorbar {
+        Colorbar {
+            colormap: self.colormap.clone(),
+            normalize: self.normalize,
+            range: self.range(),
+        }
+    }
+}
+
+/// Colormap and limits of a `Heatmap`, shown as a vertical gradient
+struct Colorbar {
+    colormap: Colormap,
+    normalize: Normalize,
+    range: (f64, f64),
+}
+
+impl Colorbar {
+    fn render(&self) -> impl IntoElement {
+        let steps = 64;
+        let gradient = (0..steps).rev().map(|step| {
+            let t = (step as f64 + 0.5) / steps as f64;
+            div().flex_1().bg(self.colormap.color(t))
+        });
+        let labels = (0..=4).rev().map(|tick| {
+            let value = self.normalize.inverse(tick as f64 / 4.0, self.range);
+            div().text_sm().child(format_value(value))
+        });
+        div()
+            .flex_none()
+            .flex()
+            .flex_row()
+            .gap_1()
+            .p_2()
+            .child(div().w(px(20.0)).flex().flex_col().children(gradient))
+            .child(div().flex().flex_col().justify_between().children(labels))
+    }
+}
+
+/// Short label for a colorbar tick
+fn format_value(value: f64) -> String {
+    if value != 0.0 && !(1e-2..1e4).contains(&value.abs()) {
+        format!("{value:.1e}")
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+/// Pearson correlation of two series of the same length
+fn correlation(a: &[f64], b: &[f64]) -> f64 {
+    let n = a.len().min(b.len()) as f64;
+    let mean_a = a.iter().sum::<f64>() / n;
+    let mean_b = b.iter().sum::<f64>() / n;
+    let (mut cov, mut var_a, mut var_b) = (0.0, 0.0, 0.0);
+    for (x, y) in a.iter().zip(b) {
+        cov += (x - mean_a) * (y - mean_b);
+        var_a += (x - mean_a).powi(2);
+        var_b += (y - mean_b).powi(2);
+    }
+    cov / (var_a * var_b).sqrt()
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our heatmap
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| HeatmapView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
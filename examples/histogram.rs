@@ -0,0 +1,488 @@
+//! Histogram Example
+//!
+//! This example demonstrates how to show the distribution of raw samples with
+//! a `Histogram` geometry implementing `GeometryAxes`. The histogram sorts
+//! its samples and bins them with a `Binning` strategy: a fixed number of
+//! bins, a fixed bin width, Sturges' rule, the Freedman–Diaconis rule, or
+//! explicit bin edges.
+//!
+//! Counts can be normalized to a density, whose integral is 1, and can be
+//! accumulated into a cumulative distribution. Bins are drawn either as
+//! translucent bars, filled over the figure with the shared `fill_layer` so
+//! that several histograms can be overlaid, or as a step outline. Each
+//! histogram computes its own extent, and the axes bounds are the union of
+//! the extents of the overlaid histograms. Changing an option plots the
+//! histograms again into the same `AxesModel` and writes its bounds.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{fill_layer, Polygon};
+use gpui::{
+    div, prelude::*, px, size, App, Application, Bounds, ClickEvent, Entity, Hsla, Window,
+    WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::{AxesContext, AxesModel};
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, GeometryAxes, Line};
+use parking_lot::RwLock;
+use std::f64::consts::TAU;
+use std::sync::Arc;
+
+/// Largest number of bins computed from the samples
+const MAX_BINS: usize = 1000;
+
+/// Main application view containing the overlaid histograms
+struct HistogramView {
+    axes_model: Arc<RwLock<AxesModel<f64, f64>>>,
+    figure: Entity<FigureView>,
+    histograms: Vec<Histogram>,
+}
+
+impl HistogramView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Histogram - binning strategies".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        // A normal distribution, and a mixture of two narrower ones
+        let normal = (0..2000).map(|i| 5.0 + 1.5 * gaussian(i)).collect();
+        let mixture = (0..1500)
+            .map(|i| {
+                let center = if i % 3 == 0 { 8.0 } else { 3.0 };
+                center + 0.7 * gaussian(10_000 + i)
+            })
+            .collect();
+        let histograms = vec![
+            Histogram::new(normal, Hsla::blue()),
+            Histogram::new(mixture, Hsla::red()),
+        ];
+
+        let grid = GridModel::from_numbers(10, 8);
+        let bounds = Histogram::bounds(&histograms);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(bounds, grid)));
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |_axes| {});
+        });
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        let view = Self {
+            axes_model,
+            figure,
+            histograms,
+        };
+        view.plot();
+        view
+    }
+
+    /// Plots the histograms into the axes model, replacing its elements and
+    /// fitting its bounds
+    fn plot(&self) {
+        let mut axes = self.axes_model.write();
+        axes.bounds = Histogram::bounds(&self.histograms);
+        axes.clear_elements();
+        for histogram in &self.histograms {
+            axes.plot(histogram.clone());
+        }
+    }
+
+    fn render_button(
+        &self,
+        id: &'static str,
+        label: String,
+        cx: &mut Context<Self>,
+        action: fn(Histogram) -> Histogram,
+    ) -> impl IntoElement {
+        div()
+            .id(id)
+            .px_2()
+            .border_1()
+            .border_color(Hsla::black())
+            .cursor_pointer()
+            .on_click(cx.listener(move |this, _: &ClickEvent, _window, cx| {
+                this.histograms = this.histograms.drain(..).map(action).collect();
+                this.plot();
+                cx.notify();
+            }))
+            .child(label)
+    }
+}
+
+impl Render for HistogramView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let fills = self.histograms.iter().flat_map(Histogram::fills).collect();
+        let bounds = self.axes_model.read().bounds;
+        let (x, y) = ((bounds.x.min, bounds.x.max), (bounds.y.min, bounds.y.max));
+
+        // All histograms share their options, the first one is shown
+        let first = &self.histograms[0];
+        let buttons = [
+            self.render_button(
+                "binning",
+                format!("Binning: {}", first.binning.name()),
+                cx,
+                |histogram| {
+                    let binning = match histogram.binning {
+                        Binning::Count(_) => Binning::Width(0.5),
+                        Binning::Width(_) => Binning::Sturges,
+                        Binning::Sturges => Binning::FreedmanDiaconis,
+                        Binning::FreedmanDiaconis => {
+                            Binning::Edges(vec![0.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 10.0])
+                        }
+                        Binning::Edges(_) => Binning::Count(20),
+                    };
+                    histogram.binning(binning)
+                },
+            ),
+            self.render_button(
+                "density",
+                format!("Density: {}", first.density),
+                cx,
+                |histogram| {
+                    let density = !histogram.density;
+                    histogram.density(density)
+                },
+            ),
+            self.render_button(
+                "cumulative",
+                format!("Cumulative: {}", first.cumulative),
+                cx,
+                |histogram| {
+                    let cumulative = !histogram.cumulative;
+                    histogram.cumulative(cumulative)
+                },
+            ),
+            self.render_button(
+                "style",
+                format!("Style: {:?}", first.style),
+                cx,
+                |histogram| {
+                    let style = match histogram.style {
+                        HistogramStyle::Bars => HistogramStyle::Step,
+                        HistogramStyle::Step => HistogramStyle::Bars,
+                    };
+                    histogram.style(style)
+                },
+            ),
+        ];
+        let bins = self
+            .histograms
+            .iter()
+            .map(|histogram| (histogram.edges().len().saturating_sub(1)).to_string())
+            .collect::<Vec<_>>()
+            .join(" / ");
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_2()
+                    .p_2()
+                    .children(buttons)
+                    .child(format!("{bins} bins")),
+            )
+            .child(
+                div()
+                    .relative()
+                    .size_full()
+                    .child(self.figure.clone())
+                    .child(fill_layer(fills, x, y)),
+            )
+    }
+}
+
+/// How bin edges are computed from the samples
+#[derive(Clone, Debug, PartialEq)]
+enum Binning {
+    /// Given number of bins between the smallest and largest sample
+    Count(usize),
+    /// Bins of the given width, aligned on multiples of the width, widened if
+    /// needed to stay within `MAX_BINS`
+    Width(f64),
+    /// `ceil(log2(n)) + 1` bins, suited to roughly normal data
+    Sturges,
+    /// Bins of width `2 IQR / cbrt(n)`, robust to outliers
+    FreedmanDiaconis,
+    /// Explicit bin edges, sorted and deduplicated before binning, with no
+    /// bins for fewer than two distinct edges. Samples outside of them are
+    /// ignored
+    Edges(Vec<f64>),
+}
+
+impl Binning {
+    fn name(&self) -> &'static str {
+        match self {
+            Binning::Count(_) => "count",
+            Binning::Width(_) => "width",
+            Binning::Sturges => "Sturges",
+            Binning::FreedmanDiaconis => "Freedman–Diaconis",
+            Binning::Edges(_) => "edges",
+        }
+    }
+
+    /// Bin edges for `sorted` samples
+    fn edges(&self, sorted: &[f64]) -> Vec<f64> {
+        let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+            return Vec::new();
+        };
+        // Identical samples still get bins, spanning one unit around them
+        let (min, max) = if max > min {
+            (min, max)
+        } else {
+            (min - 0.5, max + 0.5)
+        };
+        let count = |bins: usize| {
+            let bins = bins.clamp(1, MAX_BINS);
+            let width = (max - min) / bins as f64;
+            (0..=bins).map(|i| min + width * i as f64).collect()
+        };
+        match self {
+            Binning::Count(bins) => count(*bins),
+            Binning::Width(width) if *width > 0.0 => {
+                let width = width.max((max - min) / MAX_BINS as f64);
+                let start = (min / width).floor() as i64;
+                let end = (max / width).floor() as i64 + 1;
+                (start..=end).map(|i| i as f64 * width).collect()
+            }
+            Binning::Width(_) => Vec::new(),
+            Binning::Sturges => count((sorted.len() as f64).log2().ceil() as usize + 1),
+            Binning::FreedmanDiaconis => {
+                let quantile = |q: f64| sorted[((sorted.len() - 1) as f64 * q) as usize];
+                let width = 2.0 * (quantile(0.75) - quantile(0.25)) / (sorted.len() as f64).cbrt();
+                if width > 0.0 {
+                    count(((max - min) / width).ceil().min(MAX_BINS as f64) as usize)
+                } else {
+                    Binning::Sturges.edges(sorted)
+                }
+            }
+            Binning::Edges(edges) => {
+                let mut edges: Vec<f64> = edges.iter().copied().filter(|e| e.is_finite()).collect();
+                edges.sort_by(f64::total_cmp);
+                edges.dedup();
+                if edges.len() < 2 {
+                    return Vec::new();
+                }
+                edges
+            }
+        }
+    }
+}
+
+/// How bins are drawn
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum HistogramStyle {
+    /// Translucent filled bars
+    Bars,
+    /// Outline following the top of the bins
+    Step,
+}
+
+/// Distribution of raw samples
+#[derive(Clone)]
+struct Histogram {
+    /// Samples, sorted
+    samples: Vec<f64>,
+    binning: Binning,
+    density: bool,
+    cumulative: bool,
+    style: HistogramStyle,
+    color: Hsla,
+    alpha: f32,
+}
+
+impl Histogram {
+    fn new(mut samples: Vec<f64>, color: Hsla) -> Self {
+        samples.retain(|sample| sample.is_finite());
+        samples.sort_by(f64::total_cmp);
+        Self {
+            samples,
+            binning: Binning::Count(20),
+            density: false,
+            cumulative: false,
+            style: HistogramStyle::Bars,
+            color,
+            alpha: 0.35,
+        }
+    }
+
+    fn binning(mut self, binning: Binning) -> Self {
+        self.binning = binning;
+        self
+    }
+
+    /// Normalizes the counts so that the histogram integrates to 1, or ends
+    /// at 1 when cumulative
+    fn density(mut self, density: bool) -> Self {
+        self.density = density;
+        self
+    }
+
+    fn cumulative(mut self, cumulative: bool) -> Self {
+        self.cumulative = cumulative;
+        self
+    }
+
+    fn style(mut self, style: HistogramStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn edges(&self) -> Vec<f64> {
+        self.binning.edges(&self.samples)
+    }
+
+    /// Edges and heights of the bins
+    fn bins(&self) -> (Vec<f64>, Vec<f64>) {
+        let edges = self.edges();
+        if edges.len() < 2 {
+            return (edges, Vec::new());
+        }
+        let last = edges.len() - 1;
+        let mut counts = vec![0.0; last];
+        for &sample in &self.samples {
+            if sample < edges[0] || sample > edges[last] {
+                continue;
+            }
+            // The last bin includes its upper edge
+            let bin = edges.partition_point(|&edge| edge <= sample) - 1;
+            counts[bin.min(last - 1)] += 1.0;
+        }
+
+        // Normalized by the samples within the edges
+        let total = counts.iter().sum::<f64>().max(1.0);
+        if self.cumulative {
+            let mut sum = 0.0;
+            for count in &mut counts {
+                sum += *count;
+                *count = if self.density { sum / total } else { sum };
+            }
+        } else if self.density {
+            for (count, edge) in counts.iter_mut().zip(edges.windows(2)) {
+                *count /= total * (edge[1] - edge[0]);
+            }
+        }
+        (edges, counts)
+    }
+
+    /// X and y ranges covered by the bins
+    fn extent(&self) -> ((f64, f64), (f64, f64)) {
+        let (edges, heights) = self.bins();
+        let x = match (edges.first(), edges.last()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => (0.0, 1.0),
+        };
+        (x, (0.0, heights.iter().copied().fold(0.0, f64::max)))
+    }
+
+    /// Insides of the bars, none for the step style
+    fn fills(&self) -> Vec<Polygon> {
+        if self.style != HistogramStyle::Bars {
+            return Vec::new();
+        }
+        let color = Hsla {
+            a: self.alpha,
+            ..self.color
+        };
+        let (edges, heights) = self.bins();
+        edges
+            .windows(2)
+            .zip(heights)
+            .map(|(edge, height)| Polygon::rect((edge[0], 0.0), (edge[1], height), color))
+            .collect()
+    }
+
+    /// Bounds fitting all `histograms`, with some room above the highest bin
+    fn bounds(histograms: &[Histogram]) -> AxesBounds<f64, f64> {
+        let (x_min, x_max, y_max) = histograms.iter().map(Histogram::extent).fold(
+            (f64::MAX, f64::MIN, 0.0_f64),
+            |(x_min, x_max, y_max), ((left, right), (_, top))| {
+                (x_min.min(left), x_max.max(right), y_max.max(top))
+            },
+        );
+        AxesBounds::new(
+            AxisRange::new(x_min, x_max),
+            AxisRange::new(0.0, y_max.max(f64::EPSILON) * 1.1),
+        )
+    }
+}
+
+impl GeometryAxes for Histogram {
+    type X = f64;
+    type Y = f64;
+
+    fn render_axes(&mut self, cx: &mut AxesContext<Self::X, Self::Y>) {
+        let (edges, heights) = self.bins();
+        match self.style {
+            // Outlines only, the insides are painted by `fill_layer`
+            HistogramStyle::Bars => {
+                for (edge, &height) in edges.windows(2).zip(&heights) {
+                    let mut outline = Line::new().color(self.color);
+                    for (x, y) in [
+                        (edge[0], 0.0),
+                        (edge[0], height),
+                        (edge[1], height),
+                        (edge[1], 0.0),
+                    ] {
+                        outline.add_point(point2(x, y));
+                    }
+                    outline.render_axes(cx);
+                }
+            }
+            HistogramStyle::Step => {
+                let mut line = Line::new().color(self.color);
+                if let Some(&first) = edges.first() {
+                    line.add_point(point2(first, 0.0));
+                }
+                for (edge, &height) in edges.windows(2).zip(&heights) {
+                    line.add_point(point2(edge[0], height));
+                    line.add_point(point2(edge[1], height));
+                }
+                if let Some(&last) = edges.last() {
+                    line.add_point(point2(last, 0.0));
+                }
+                line.render_axes(cx);
+            }
+        }
+    }
+}
+
+/// Deterministic sample of a standard normal distribution (Box–Muller)
+fn gaussian(seed: usize) -> f64 {
+    let uniform = |seed: usize| {
+        ((seed as f64 * 12.9898).sin() * 43758.5453)
+            .fract()
+            .abs()
+            .max(1e-12)
+    };
+    let (u, v) = (uniform(2 * seed + 1), uniform(2 * seed + 2));
+    (-2.0 * u.ln()).sqrt() * (TAU * v).cos()
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our histograms
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| HistogramView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
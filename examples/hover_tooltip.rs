@@ -0,0 +1,318 @@
+//! Hover Tooltip Example
+//!
+//! This example demonstrates how to inspect a plot with the mouse. While the
+//! cursor is over the `FigureView`, a crosshair follows it and its position is
+//! shown in data coordinates. A tooltip shows the label and coordinates of the
+//! nearest point of the nearest series, which is also highlighted.
+//!
+//! The nearest point is found with `Series::nearest`, which measures distances
+//! in axes units so that both axes weigh the same whatever their ranges. Mouse
+//! positions are mapped to data coordinates from the bounds of the figure
+//! element and the current bounds of the `AxesModel`, with the shared
+//! `FigureArea`. The crosshair and tooltip are cleared when the cursor leaves
+//! the figure.
+//!
+//! The series are plotted once. The crosshair and the highlight circle, sized
+//! in pixels, are painted over the figure with the shared `paint_layer`, so
+//! moving the mouse does not touch the figure model.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{
+    bounds_tracker, paint_layer, stroke_path, ElementBounds, FigureArea, LineCap, Stroke,
+};
+use gpui::{
+    div, point, prelude::*, px, size, App, Application, Bounds, Entity, Hsla, MouseMoveEvent,
+    Pixels, Point, Window, WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::{AxesContext, AxesModel};
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, GeometryAxes, Line};
+use parking_lot::RwLock;
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+const X_RANGE: (f64, f64) = (0.0, 2.0 * PI);
+const Y_RANGE: (f64, f64) = (-1.5, 1.5);
+
+/// Maximum distance in axes units for a point to get a tooltip
+const HOVER_DISTANCE: f64 = 0.05;
+
+/// Radius of the circle around the hovered point, in pixels
+const HIGHLIGHT_RADIUS: f32 = 5.0;
+
+/// Main application view containing the plot and its hover feedback
+struct HoverTooltipView {
+    axes_model: Arc<RwLock<AxesModel<f64, f64>>>,
+    figure: Entity<FigureView>,
+    series: Vec<Series>,
+    /// Bounds of the figure element, updated on every paint
+    element_bounds: ElementBounds,
+    /// Cursor position, in pixels and in data coordinates
+    cursor: Option<(Point<Pixels>, (f64, f64))>,
+}
+
+impl HoverTooltipView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Hover Tooltip - move the mouse over the plot".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        let x_range = AxisRange::new(X_RANGE.0, X_RANGE.1);
+        let y_range = AxisRange::new(Y_RANGE.0, Y_RANGE.1);
+        let axes_bounds = AxesBounds::new(x_range, y_range);
+
+        let grid = GridModel::from_numbers(10, 8);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(axes_bounds, grid)));
+
+        let series = vec![
+            Series::sample("sin(x)", Hsla::blue(), f64::sin),
+            Series::sample("cos(x)", Hsla::red(), f64::cos),
+        ];
+
+        // Plot the series once, the hover feedback is painted over the figure
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |axes| {
+                for series in &series {
+                    axes.plot(series.clone());
+                }
+            });
+        });
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        Self {
+            axes_model,
+            figure,
+            series,
+            element_bounds: ElementBounds::default(),
+            cursor: None,
+        }
+    }
+
+    /// Current x and y ranges of the axes model
+    fn ranges(&self) -> ((f64, f64), (f64, f64)) {
+        let bounds = self.axes_model.read().bounds;
+        ((bounds.x.min, bounds.x.max), (bounds.y.min, bounds.y.max))
+    }
+
+    /// Maps a position in pixels to data coordinates
+    fn to_data(&self, position: Point<Pixels>) -> Option<(f64, f64)> {
+        let area = FigureArea::new(&self.element_bounds)?;
+        let (x_range, y_range) = self.ranges();
+        Some(area.data(position, x_range, y_range))
+    }
+
+    /// Nearest point of the nearest series within `HOVER_DISTANCE`
+    fn hovered(&self) -> Option<(&Series, (f64, f64))> {
+        let (_, target) = self.cursor?;
+        let ranges = self.ranges();
+        self.series
+            .iter()
+            .filter_map(|series| {
+                let (point, distance) = series.nearest(target, ranges)?;
+                Some((series, point, distance))
+            })
+            .filter(|(_, _, distance)| *distance <= HOVER_DISTANCE)
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+            .map(|(series, point, _)| (series, point))
+    }
+
+    fn on_mouse_move(
+        &mut self,
+        event: &MouseMoveEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.cursor = self
+            .to_data(event.position)
+            .map(|data| (event.position, data));
+        cx.notify();
+    }
+
+    /// Clears the cursor when the mouse leaves the figure
+    fn on_hover(&mut self, hovered: &bool, _window: &mut Window, cx: &mut Context<Self>) {
+        if !*hovered && self.cursor.take().is_some() {
+            cx.notify();
+        }
+    }
+
+    /// Crosshair through the cursor and circle around the hovered point
+    fn render_marks(&self) -> Option<impl IntoElement> {
+        let (_, (x, y)) = self.cursor?;
+        let (x_range, y_range) = self.ranges();
+        let highlight = self.hovered().map(|(series, point)| (point, series.color));
+        Some(paint_layer(x_range, y_range, move |window, to_pixels| {
+            let crosshair = Stroke::new(
+                Vec::new(),
+                Hsla {
+                    a: 0.5,
+                    ..Hsla::black()
+                },
+            );
+            for line in [
+                [(x, y_range.0), (x, y_range.1)],
+                [(x_range.0, y), (x_range.1, y)],
+            ] {
+                stroke_path(window, &line.map(to_pixels), &crosshair);
+            }
+
+            if let Some((center, color)) = highlight {
+                let center = to_pixels(center);
+                let circle: Vec<_> = (0..=24)
+                    .map(|i| {
+                        let angle = i as f32 / 24.0 * std::f32::consts::TAU;
+                        point(
+                            center.x + px(HIGHLIGHT_RADIUS * angle.cos()),
+                            center.y + px(HIGHLIGHT_RADIUS * angle.sin()),
+                        )
+                    })
+                    .collect();
+                let stroke = Stroke::new(Vec::new(), color)
+                    .width(1.5)
+                    .cap(LineCap::Round);
+                stroke_path(window, &circle, &stroke);
+            }
+        }))
+    }
+
+    /// Coordinates of the cursor and tooltip of the hovered point
+    fn render_overlay(&self) -> Option<impl IntoElement> {
+        let (position, (x, y)) = self.cursor?;
+        let (left, top) = FigureArea::new(&self.element_bounds)?.local(position);
+
+        let tooltip = self.hovered().map(|(series, (hx, hy))| {
+            div()
+                .absolute()
+                .left(px(left + 12.0))
+                .top(px(top + 12.0))
+                .p_1()
+                .bg(gpui::white())
+                .border_1()
+                .border_color(series.color)
+                .text_sm()
+                .child(format!("{}: x = {hx:.3}, y = {hy:.3}", series.label))
+        });
+
+        Some(
+            div()
+                .absolute()
+                .size_full()
+                .child(
+                    div()
+                        .absolute()
+                        .right(px(8.0))
+                        .bottom(px(8.0))
+                        .text_sm()
+                        .child(format!("x = {x:.3}, y = {y:.3}")),
+                )
+                .children(tooltip),
+        )
+    }
+}
+
+impl Render for HoverTooltipView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Record the bounds of the figure element to map mouse positions
+        let tracker = bounds_tracker(&self.element_bounds, cx.entity_id());
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(
+                div()
+                    .id("figure")
+                    .relative()
+                    .size_full()
+                    .on_mouse_move(cx.listener(Self::on_mouse_move))
+                    .on_hover(cx.listener(Self::on_hover))
+                    .child(self.figure.clone())
+                    .child(tracker)
+                    .children(self.render_marks())
+                    .children(self.render_overlay()),
+            )
+    }
+}
+
+/// A labeled curve supporting nearest point queries
+#[derive(Clone)]
+struct Series {
+    label: String,
+    color: Hsla,
+    points: Vec<(f64, f64)>,
+}
+
+impl Series {
+    /// Samples `f` over the x range of the axes
+    fn sample(label: &str, color: Hsla, f: impl Fn(f64) -> f64) -> Self {
+        let step = 0.1;
+        let count = ((X_RANGE.1 - X_RANGE.0) / step) as usize;
+        let points = (0..=count)
+            .map(|i| {
+                let x = X_RANGE.0 + i as f64 * step;
+                (x, f(x))
+            })
+            .collect();
+        Self {
+            label: label.to_string(),
+            color,
+            points,
+        }
+    }
+
+    /// Nearest point to `target` and its distance in axes units, given the
+    /// x and y ranges of the axes
+    fn nearest(
+        &self,
+        target: (f64, f64),
+        (x_range, y_range): ((f64, f64), (f64, f64)),
+    ) -> Option<((f64, f64), f64)> {
+        let distance = |(x, y): (f64, f64)| {
+            let dx = (x - target.0) / (x_range.1 - x_range.0);
+            let dy = (y - target.1) / (y_range.1 - y_range.0);
+            dx.hypot(dy)
+        };
+        self.points
+            .iter()
+            .map(|&point| (point, distance(point)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
+
+impl GeometryAxes for Series {
+    type X = f64;
+    type Y = f64;
+
+    fn render_axes(&mut self, cx: &mut AxesContext<Self::X, Self::Y>) {
+        let mut line = Line::new().color(self.color);
+        for &(x, y) in &self.points {
+            line.add_point(point2(x, y));
+        }
+        line.render_axes(cx);
+    }
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our interactive plot
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| HoverTooltipView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
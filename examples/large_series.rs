@@ -0,0 +1,379 @@
+//! Large Series Example
+//!
+//! This example demonstrates how to keep a 10-million-sample recording
+//! interactive by decimating it before it is handed to `Line`. Only the
+//! samples inside the visible x range are considered, and they are reduced to
+//! about two points per pixel column of the figure, either by keeping the
+//! minimum and maximum of each column or with the Largest-Triangle-Three-
+//! Buckets (LTTB) algorithm.
+//!
+//! The decimated line is recomputed when the visible range, the width of the
+//! figure or the method changes. The width is only known once the figure has
+//! been painted, so a new frame is requested whenever the figure is resized.
+//! Use the mouse wheel to zoom along x around the cursor and double-click to
+//! reset.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{bounds_tracker, ElementBounds, FigureArea};
+use gpui::{
+    div, prelude::*, px, size, App, Application, Bounds, ClickEvent, Entity, Hsla, MouseButton,
+    MouseDownEvent, ScrollWheelEvent, Window, WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::{AxesContext, AxesModel};
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, GeometryAxes, Line};
+use parking_lot::RwLock;
+use std::f64::consts::TAU;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// Number of samples in the recording
+const SAMPLES: usize = 10_000_000;
+/// Number of pixel columns assumed before the figure has been laid out
+const DEFAULT_COLUMNS: usize = 1000;
+
+/// Main application view containing the large recording
+struct LargeSeriesView {
+    axes_model: Arc<RwLock<AxesModel<f64, f64>>>,
+    figure: Entity<FigureView>,
+    recording: Arc<Recording>,
+    visible: (f64, f64),
+    method: Decimation,
+    /// Bounds of the figure element, updated on every paint
+    element_bounds: ElementBounds,
+    /// Inputs of the last decimation, to recompute it only when they change
+    decimated: Option<((f64, f64), usize, Decimation)>,
+    drawn: usize,
+}
+
+impl LargeSeriesView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Large Series - 10M samples, decimated".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        let recording = Arc::new(Recording::synthetic(SAMPLES));
+        let visible = recording.extent();
+
+        let grid = GridModel::from_numbers(10, 8);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(Self::bounds(visible), grid)));
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |_| {});
+        });
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        Self {
+            axes_model,
+            figure,
+            recording,
+            visible,
+            method: Decimation::MinMax,
+            element_bounds: ElementBounds::default(),
+            decimated: None,
+            drawn: 0,
+        }
+    }
+
+    fn bounds(visible: (f64, f64)) -> AxesBounds<f64, f64> {
+        AxesBounds::new(
+            AxisRange::new(visible.0, visible.1),
+            AxisRange::new(-2.0, 2.0),
+        )
+    }
+
+    /// Current width of the figure in pixels
+    fn columns(&self) -> usize {
+        FigureArea::new(&self.element_bounds)
+            .map(|area| area.width() as usize)
+            .filter(|columns| *columns > 0)
+            .unwrap_or(DEFAULT_COLUMNS)
+    }
+
+    /// Decimates the visible samples again if the view changed
+    fn update_plot(&mut self) {
+        let key = (self.visible, self.columns(), self.method);
+        if self.decimated == Some(key) {
+            return;
+        }
+        self.decimated = Some(key);
+
+        let points = self.recording.decimate(self.visible, key.1, self.method);
+        self.drawn = points.len();
+
+        let mut axes = self.axes_model.write();
+        axes.bounds = Self::bounds(self.visible);
+        axes.clear_elements();
+        axes.plot(DecimatedLine {
+            points,
+            color: Hsla::blue(),
+        });
+    }
+
+    fn on_scroll(
+        &mut self,
+        event: &ScrollWheelEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(area) = FigureArea::new(&self.element_bounds) else {
+            return;
+        };
+        let (fraction, _) = area.fraction(event.position);
+        let delta = f32::from(event.delta.pixel_delta(px(16.0)).y) as f64;
+        let factor = (-delta * 0.005).exp();
+
+        // Zoom around the cursor, without going beyond the recording
+        let (min, max) = self.visible;
+        let center = min + (max - min) * fraction;
+        let (start, end) = self.recording.extent();
+        let visible = (
+            (center - (center - min) * factor).max(start),
+            (center + (max - center) * factor).min(end),
+        );
+        if visible.1 - visible.0 > self.recording.dt * 10.0 {
+            self.visible = visible;
+            cx.notify();
+        }
+    }
+
+    fn on_mouse_down(
+        &mut self,
+        event: &MouseDownEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if event.click_count == 2 {
+            self.visible = self.recording.extent();
+            cx.notify();
+        }
+    }
+}
+
+impl Render for LargeSeriesView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.update_plot();
+
+        let visible_samples = self.recording.index_range(self.visible).len();
+        let status = format!(
+            "{visible_samples} samples visible, {} points drawn",
+            self.drawn
+        );
+        let method = div()
+            .id("method")
+            .px_2()
+            .border_1()
+            .border_color(Hsla::black())
+            .cursor_pointer()
+            .on_click(cx.listener(|this, _: &ClickEvent, _window, cx| {
+                this.method = match this.method {
+                    Decimation::MinMax => Decimation::Lttb,
+                    Decimation::Lttb => Decimation::MinMax,
+                };
+                cx.notify();
+            }))
+            .child(format!("Method: {:?}", self.method));
+
+        // Record the bounds of the figure element to map mouse positions
+        let tracker = bounds_tracker(&self.element_bounds, cx.entity_id());
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_2()
+                    .p_2()
+                    .child(method)
+                    .child(status),
+            )
+            .child(
+                div()
+                    .relative()
+                    .size_full()
+                    .on_scroll_wheel(cx.listener(Self::on_scroll))
+                    .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+                    .child(self.figure.clone())
+                    .child(tracker),
+            )
+    }
+}
+
+/// How samples are reduced to about two points per pixel column
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Decimation {
+    /// Minimum and maximum of each column, in the order they occur
+    MinMax,
+    /// Largest-Triangle-Three-Buckets
+    Lttb,
+}
+
+/// Uniformly sampled recording
+struct Recording {
+    start: f64,
+    dt: f64,
+    samples: Vec<f64>,
+}
+
+impl Recording {
+    /// A chirp with bursts of noise, sampled at 1 MHz
+    fn synthetic(count: usize) -> Self {
+        let dt = 1e-6;
+        let samples = (0..count)
+            .map(|i| {
+                let t = i as f64 * dt;
+                let chirp = (TAU * (5.0 + 200.0 * t) * t).sin();
+                let noise = ((i as f64 * 12.9898).sin() * 43758.5453).fract();
+                let burst = if (t * 2.0).fract() < 0.05 { 0.8 } else { 0.05 };
+                chirp + burst * noise
+            })
+            .collect();
+        Self {
+            start: 0.0,
+            dt,
+            samples,
+        }
+    }
+
+    fn x(&self, index: usize) -> f64 {
+        self.start + index as f64 * self.dt
+    }
+
+    fn extent(&self) -> (f64, f64) {
+        (self.start, self.x(self.samples.len().saturating_sub(1)))
+    }
+
+    /// Indices of the samples between `min` and `max`
+    fn index_range(&self, (min, max): (f64, f64)) -> Range<usize> {
+        let index = |x: f64| ((x - self.start) / self.dt).clamp(0.0, self.samples.len() as f64);
+        index(min).floor() as usize..(index(max).ceil() as usize + 1).min(self.samples.len())
+    }
+
+    /// Visible samples reduced for `columns` pixel columns
+    fn decimate(&self, visible: (f64, f64), columns: usize, method: Decimation) -> Vec<(f64, f64)> {
+        let range = self.index_range(visible);
+        if range.len() <= 2 * columns.max(1) {
+            return range.map(|i| (self.x(i), self.samples[i])).collect();
+        }
+        match method {
+            Decimation::MinMax => self.min_max(range, columns),
+            Decimation::Lttb => self.lttb(range, 2 * columns),
+        }
+    }
+
+    fn min_max(&self, range: Range<usize>, columns: usize) -> Vec<(f64, f64)> {
+        let len = range.len();
+        let mut points = Vec::with_capacity(2 * columns);
+        for column in 0..columns {
+            let from = range.start + len * column / columns;
+            let to = range.start + len * (column + 1) / columns;
+            let slice = &self.samples[from..to];
+            let Some((low, _)) = slice.iter().enumerate().min_by(|a, b| a.1.total_cmp(b.1)) else {
+                continue;
+            };
+            let (high, _) = slice
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .unwrap_or((low, &0.0));
+            for index in [low.min(high), low.max(high)] {
+                points.push((self.x(from + index), slice[index]));
+            }
+        }
+        points
+    }
+
+    fn lttb(&self, range: Range<usize>, threshold: usize) -> Vec<(f64, f64)> {
+        let point = |i: usize| (self.x(i), self.samples[i]);
+        let (first, last) = (range.start, range.end - 1);
+        let buckets = threshold.max(3) - 2;
+        let bucket_size = (range.len() - 2) as f64 / buckets as f64;
+        let bucket = |b: usize| {
+            let from = first + 1 + (b as f64 * bucket_size) as usize;
+            let to = first + 1 + ((b + 1) as f64 * bucket_size) as usize;
+            from..to.min(last)
+        };
+
+        let mut points = Vec::with_capacity(threshold);
+        points.push(point(first));
+        let mut previous = point(first);
+        for b in 0..buckets {
+            // Average of the next bucket, or the last point for the last bucket
+            let next = if b + 1 < buckets {
+                let next = bucket(b + 1);
+                let count = next.len().max(1) as f64;
+                let (sx, sy) = next
+                    .map(point)
+                    .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+                (sx / count, sy / count)
+            } else {
+                point(last)
+            };
+
+            // Keep the point forming the largest triangle with its neighbours
+            let selected = bucket(b).map(point).max_by(|a, c| {
+                let area = |p: &(f64, f64)| {
+                    ((previous.0 - next.0) * (p.1 - previous.1)
+                        - (previous.0 - p.0) * (next.1 - previous.1))
+                        .abs()
+                };
+                area(a).total_cmp(&area(c))
+            });
+            if let Some(selected) = selected {
+                points.push(selected);
+                previous = selected;
+            }
+        }
+        points.push(point(last));
+        points
+    }
+}
+
+/// Points of a recording already reduced for display
+#[derive(Clone)]
+struct DecimatedLine {
+    points: Vec<(f64, f64)>,
+    color: Hsla,
+}
+
+impl GeometryAxes for DecimatedLine {
+    type X = f64;
+    type Y = f64;
+
+    fn render_axes(&mut self, cx: &mut AxesContext<Self::X, Self::Y>) {
+        let mut line = Line::new().color(self.color);
+        for &(x, y) in &self.points {
+            line.add_point(point2(x, y));
+        }
+        line.render_axes(cx);
+    }
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our large recording
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| LargeSeriesView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
@@ -0,0 +1,328 @@
+//! Legend Example
+//!
+//! This example demonstrates how to label the curves of a plot with a legend.
+//! Each `Series` carries a label and a `Stroke` style with its color, width
+//! and dashes, and the `Legend` collects them into entries made of a swatch
+//! and the label text. The curves are painted over the figure with the shared
+//! `stroke_layer`, and each swatch is a short sample of the same stroke.
+//!
+//! The legend is laid out with gpui elements on top of the `FigureView`. It can
+//! be placed in a corner of the plot, outside on the right, or in the corner
+//! that hides the fewest data points, and can use several columns. Clicking an
+//! entry toggles the visibility of its series.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{stroke_layer, stroke_path, LineCap, Stroke};
+use gpui::{
+    canvas, div, point, prelude::*, px, size, App, Application, Bounds, ClickEvent, Entity, Hsla,
+    Window, WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::AxesModel;
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{AxesBounds, AxisRange};
+use parking_lot::RwLock;
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+const X_MIN: f64 = 0.0;
+const X_MAX: f64 = 2.0 * PI;
+const Y_MIN: f64 = -1.5;
+const Y_MAX: f64 = 1.5;
+
+/// Main application view containing the plot and its legend
+struct LegendPlotView {
+    figure: Entity<FigureView>,
+    series: Vec<Series>,
+    legend: Legend,
+}
+
+impl LegendPlotView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Legend - click an entry to hide it".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        let x_range = AxisRange::new(X_MIN, X_MAX);
+        let y_range = AxisRange::new(Y_MIN, Y_MAX);
+        let axes_bounds = AxesBounds::new(x_range, y_range);
+
+        let grid = GridModel::from_numbers(10, 8);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(axes_bounds, grid)));
+
+        // The axes only hold the grid, the curves are painted over the figure
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |_axes| {});
+        });
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        let style = |color: Hsla| Stroke::new(Vec::new(), color).width(2.0);
+        let series = vec![
+            Series::sample("sin(x)", style(Hsla::blue()), f64::sin),
+            Series::sample(
+                "cos(x)",
+                style(Hsla::red()).dashes(vec![8.0, 4.0]),
+                f64::cos,
+            ),
+            Series::sample(
+                "sin(2x) / 2",
+                style(Hsla::green())
+                    .cap(LineCap::Round)
+                    .dashes(vec![1.0, 5.0]),
+                |x| (2.0 * x).sin() / 2.0,
+            ),
+            Series::sample("x / 2π", style(Hsla::black()).width(1.0), |x| {
+                x / (2.0 * PI)
+            }),
+        ];
+
+        Self {
+            figure,
+            series,
+            legend: Legend::new().placement(LegendPlacement::Best).columns(2),
+        }
+    }
+
+    fn render_legend(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let rows = self
+            .series
+            .len()
+            .div_ceil(self.legend.columns.max(1))
+            .max(1);
+        let columns = self.series.chunks(rows).enumerate().map(|(column, chunk)| {
+            div()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .children(chunk.iter().enumerate().map(|(row, series)| {
+                    let index = column * rows + row;
+                    let mut swatch = series.style.clone();
+                    let text = if series.visible {
+                        Hsla::black()
+                    } else {
+                        swatch.color.a = 0.25;
+                        Hsla {
+                            a: 0.4,
+                            ..Hsla::black()
+                        }
+                    };
+                    div()
+                        .id(("legend-entry", index))
+                        .flex()
+                        .flex_row()
+                        .items_center()
+                        .gap_2()
+                        .cursor_pointer()
+                        .on_click(cx.listener(move |this, _: &ClickEvent, _window, cx| {
+                            this.series[index].visible = !this.series[index].visible;
+                            cx.notify();
+                        }))
+                        .child(render_swatch(swatch))
+                        .child(div().text_color(text).child(series.label.clone()))
+                }))
+        });
+
+        let legend = div()
+            .flex()
+            .flex_row()
+            .gap_3()
+            .p_2()
+            .bg(gpui::white())
+            .border_1()
+            .border_color(Hsla {
+                a: 0.3,
+                ..Hsla::black()
+            })
+            .children(columns);
+
+        // Corners are relative to the figure area, with a margin for the axes
+        let margin = px(48.0);
+        match self.legend.resolve(&self.series) {
+            LegendPlacement::TopLeft => legend.absolute().top(margin).left(margin),
+            LegendPlacement::TopRight => legend.absolute().top(margin).right(margin),
+            LegendPlacement::BottomLeft => legend.absolute().bottom(margin).left(margin),
+            LegendPlacement::BottomRight => legend.absolute().bottom(margin).right(margin),
+            LegendPlacement::OutsideRight | LegendPlacement::Best => legend.flex_none(),
+        }
+    }
+}
+
+impl Render for LegendPlotView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Only the visible series are painted
+        let strokes = self
+            .series
+            .iter()
+            .filter(|series| series.visible)
+            .map(Series::stroke)
+            .collect();
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .relative()
+            .flex()
+            .flex_row()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(
+                div()
+                    .flex_1()
+                    .relative()
+                    .child(self.figure.clone())
+                    .child(stroke_layer(strokes, (X_MIN, X_MAX), (Y_MIN, Y_MAX))),
+            )
+            .child(self.render_legend(cx))
+    }
+}
+
+/// Sample of `style` across a small element, drawn in front of a legend label
+fn render_swatch(style: Stroke) -> impl IntoElement {
+    canvas(
+        |_bounds, _window, _cx| {},
+        move |bounds, _state, window, _cx| {
+            let y = bounds.origin.y + px(f32::from(bounds.size.height) / 2.0);
+            let (left, right) = (bounds.origin.x, bounds.origin.x + bounds.size.width);
+            let margin = px(style.width / 2.0);
+            stroke_path(
+                window,
+                &[point(left + margin, y), point(right - margin, y)],
+                &style,
+            );
+        },
+    )
+    .w(px(24.0))
+    .h(px(12.0))
+}
+
+/// A labeled curve
+#[derive(Clone)]
+struct Series {
+    label: String,
+    /// Color, width and dashes of the curve, without points
+    style: Stroke,
+    points: Vec<(f64, f64)>,
+    visible: bool,
+}
+
+impl Series {
+    /// Samples `f` over the x range of the axes
+    fn sample(label: &str, style: Stroke, f: impl Fn(f64) -> f64) -> Self {
+        let step = 0.05;
+        let count = ((X_MAX - X_MIN) / step) as usize;
+        let points = (0..=count)
+            .map(|i| {
+                let x = X_MIN + i as f64 * step;
+                (x, f(x))
+            })
+            .collect();
+        Self {
+            label: label.to_string(),
+            style,
+            points,
+            visible: true,
+        }
+    }
+
+    /// Curve through the points, with the style of the series
+    fn stroke(&self) -> Stroke {
+        Stroke {
+            points: self.points.clone(),
+            ..self.style.clone()
+        }
+    }
+}
+
+/// Where the legend is drawn
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum LegendPlacement {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    OutsideRight,
+    /// The corner covering the fewest data points
+    Best,
+}
+
+/// Legend listing the label and color of each series
+#[derive(Clone, Debug)]
+struct Legend {
+    placement: LegendPlacement,
+    columns: usize,
+}
+
+impl Legend {
+    fn new() -> Self {
+        Self {
+            placement: LegendPlacement::TopRight,
+            columns: 1,
+        }
+    }
+
+    fn placement(mut self, placement: LegendPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Resolves `Best` to the corner with the fewest visible data points
+    fn resolve(&self, series: &[Series]) -> LegendPlacement {
+        if self.placement != LegendPlacement::Best {
+            return self.placement;
+        }
+
+        // The legend covers about a third of the width and a quarter of the height
+        let left = X_MIN + (X_MAX - X_MIN) / 3.0;
+        let right = X_MAX - (X_MAX - X_MIN) / 3.0;
+        let bottom = Y_MIN + (Y_MAX - Y_MIN) / 4.0;
+        let top = Y_MAX - (Y_MAX - Y_MIN) / 4.0;
+        let corners = [
+            (LegendPlacement::TopRight, right, X_MAX, top, Y_MAX),
+            (LegendPlacement::TopLeft, X_MIN, left, top, Y_MAX),
+            (LegendPlacement::BottomRight, right, X_MAX, Y_MIN, bottom),
+            (LegendPlacement::BottomLeft, X_MIN, left, Y_MIN, bottom),
+        ];
+
+        let covered = |x0: f64, x1: f64, y0: f64, y1: f64| {
+            series
+                .iter()
+                .filter(|series| series.visible)
+                .flat_map(|series| series.points.iter())
+                .filter(|(x, y)| (x0..=x1).contains(x) && (y0..=y1).contains(y))
+                .count()
+        };
+        corners
+            .into_iter()
+            .min_by_key(|&(_, x0, x1, y0, y1)| covered(x0, x1, y0, y1))
+            .map(|(placement, ..)| placement)
+            .unwrap_or(LegendPlacement::OutsideRight)
+    }
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our plot and legend
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| LegendPlotView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
@@ -0,0 +1,198 @@
+//! Line Styles Example
+//!
+//! This example demonstrates how to tell curves apart without relying on
+//! color, so plots stay readable when printed in grayscale. Each curve is a
+//! `Stroke` from the examples' common module, with a width in pixels, a
+//! `LineCap` for its ends, a `LineJoin` for its corners and a dash pattern
+//! (solid, dashed, dotted, dash-dot or a custom on/off array). Strokes are
+//! outlined in pixels and painted over the figure with `stroke_layer`, so
+//! their width does not change with the axes ranges or the window size.
+//!
+//! Dash lengths are multiples of the stroke width, so a pattern keeps its
+//! proportions on thin and thick lines, and round caps turn dashes of zero
+//! length into dots. Thick zigzags along the bottom show the three joins.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{stroke_layer, LineCap, LineJoin, Stroke};
+use gpui::{
+    div, hsla, prelude::*, px, size, App, Application, Bounds, Entity, Hsla, Window, WindowBounds,
+    WindowOptions,
+};
+use gpui_plot::figure::axes::AxesModel;
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{AxesBounds, AxisRange};
+use parking_lot::RwLock;
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+const X_MIN: f64 = 0.0;
+const X_MAX: f64 = 2.0 * PI;
+const Y_MIN: f64 = -1.5;
+const Y_MAX: f64 = 1.5;
+
+/// Main application view containing the styled curves
+struct LineStylesView {
+    figure: Entity<FigureView>,
+    strokes: Vec<Stroke>,
+}
+
+impl LineStylesView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Line Styles - reference vs measured".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        let x_range = AxisRange::new(X_MIN, X_MAX);
+        let y_range = AxisRange::new(Y_MIN, Y_MAX);
+        let axes_bounds = AxesBounds::new(x_range, y_range);
+
+        let grid = GridModel::from_numbers(10, 8);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(axes_bounds, grid)));
+
+        // The axes only hold the grid, strokes are painted over the figure
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |_axes| {});
+        });
+
+        let styles = [
+            (DashPattern::Solid, 3.0, LineCap::Round, Hsla::black()),
+            (DashPattern::Dashed, 2.0, LineCap::Butt, Hsla::black()),
+            (
+                DashPattern::Dotted,
+                3.0,
+                LineCap::Round,
+                hsla(0.0, 0.0, 0.3, 1.0),
+            ),
+            (
+                DashPattern::DashDot,
+                1.5,
+                LineCap::Square,
+                hsla(0.0, 0.0, 0.3, 1.0),
+            ),
+            (
+                DashPattern::Custom(vec![8.0, 2.0, 2.0, 2.0, 2.0, 2.0]),
+                1.5,
+                LineCap::Butt,
+                hsla(0.0, 0.0, 0.5, 1.0),
+            ),
+        ];
+
+        // The same curve with decreasing amplitude, one style each
+        let mut strokes: Vec<Stroke> = styles
+            .into_iter()
+            .enumerate()
+            .map(|(i, (dash, width, cap, color))| {
+                let amplitude = 1.0 - 0.15 * i as f64;
+                let points = (0..=314)
+                    .map(|step| {
+                        let x = X_MIN + (X_MAX - X_MIN) * step as f64 / 314.0;
+                        (x, amplitude * x.sin())
+                    })
+                    .collect();
+                Stroke::new(points, color)
+                    .width(width)
+                    .cap(cap)
+                    .join(LineJoin::Round)
+                    .dashes(dash.lengths(width))
+            })
+            .collect();
+
+        // One zigzag per join, under the curves
+        let joins = [
+            (LineJoin::Miter, LineCap::Butt),
+            (LineJoin::Round, LineCap::Round),
+            (LineJoin::Bevel, LineCap::Square),
+        ];
+        for (i, (join, cap)) in joins.into_iter().enumerate() {
+            let left = 0.4 + 2.0 * i as f64;
+            let points = (0..5)
+                .map(|corner| {
+                    let y = if corner % 2 == 0 { -1.35 } else { -1.15 };
+                    (left + 0.35 * corner as f64, y)
+                })
+                .collect();
+            strokes.push(
+                Stroke::new(points, hsla(0.0, 0.0, 0.2, 1.0))
+                    .width(8.0)
+                    .cap(cap)
+                    .join(join),
+            );
+        }
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        Self { figure, strokes }
+    }
+}
+
+impl Render for LineStylesView {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(
+                div()
+                    .relative()
+                    .size_full()
+                    .child(self.figure.clone())
+                    .child(stroke_layer(
+                        self.strokes.clone(),
+                        (X_MIN, X_MAX),
+                        (Y_MIN, Y_MAX),
+                    )),
+            )
+    }
+}
+
+/// Dash pattern of a stroke as alternating on/off lengths, in multiples of
+/// the stroke width
+#[derive(Clone, Debug, PartialEq)]
+enum DashPattern {
+    Solid,
+    Dashed,
+    Dotted,
+    DashDot,
+    Custom(Vec<f32>),
+}
+
+impl DashPattern {
+    /// On/off lengths in pixels for a stroke of `width` pixels, or an empty
+    /// array for a solid stroke
+    fn lengths(&self, width: f32) -> Vec<f32> {
+        let lengths = match self {
+            DashPattern::Solid => return Vec::new(),
+            DashPattern::Dashed => vec![6.0, 3.0],
+            // Dots of zero length, drawn by round caps
+            DashPattern::Dotted => vec![0.0, 2.5],
+            DashPattern::DashDot => vec![6.0, 2.5, 0.5, 2.5],
+            DashPattern::Custom(lengths) => lengths.clone(),
+        };
+        lengths.into_iter().map(|length| length * width).collect()
+    }
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our styled curves
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| LineStylesView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
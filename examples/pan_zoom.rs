@@ -0,0 +1,340 @@
+//! Pan and Zoom Example
+//!
+//! This example demonstrates how to make a `FigureView` interactive:
+//!
+//! - mouse wheel zooms around the cursor, or along a single axis when the
+//!   cursor is over the bottom (x) or left (y) axis margin,
+//! - dragging with the left button pans,
+//! - dragging with shift held zooms into the selected box,
+//! - double-clicking resets to the original bounds.
+//!
+//! Mouse positions are mapped to data coordinates from the bounds of the
+//! figure element with the shared `FigureArea`. Every change is written to
+//! the bounds of the shared `Arc<RwLock<AxesModel>>`, so anything holding the
+//! axes model sees them. The plotted data is built once and left untouched.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{bounds_tracker, ElementBounds, FigureArea};
+use gpui::{
+    div, prelude::*, px, size, App, Application, Bounds, Entity, Hsla, MouseButton, MouseDownEvent,
+    MouseMoveEvent, MouseUpEvent, Pixels, Point, ScrollWheelEvent, Window, WindowBounds,
+    WindowOptions,
+};
+use gpui_plot::figure::axes::AxesModel;
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, Line};
+use parking_lot::RwLock;
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+/// Width in pixels of the margins treated as the x and y axes
+const AXIS_MARGIN: f32 = 40.0;
+
+/// Main application view containing the interactive plot
+struct PanZoomView {
+    axes_model: Arc<RwLock<AxesModel<f64, f64>>>,
+    figure: Entity<FigureView>,
+    home: Viewport,
+    viewport: Viewport,
+    /// Bounds of the figure element, updated on every paint
+    element_bounds: ElementBounds,
+    drag: Option<Drag>,
+}
+
+impl PanZoomView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Pan and Zoom - wheel, drag, shift+drag".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        let home = Viewport {
+            x: (0.0, 4.0 * PI),
+            y: (-1.5, 1.5),
+        };
+        let grid = GridModel::from_numbers(10, 8);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(home.bounds(), grid)));
+
+        // The chirp is plotted once, zooming and panning only change the bounds
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |axes| {
+                axes.plot(chirp());
+            });
+        });
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        Self {
+            axes_model,
+            figure,
+            home,
+            viewport: home,
+            element_bounds: ElementBounds::default(),
+            drag: None,
+        }
+    }
+
+    /// Writes the current viewport back to the shared axes model
+    fn set_viewport(&mut self, viewport: Viewport, cx: &mut Context<Self>) {
+        if !viewport.is_valid() {
+            return;
+        }
+        self.viewport = viewport;
+        self.axes_model.write().bounds = viewport.bounds();
+        cx.notify();
+    }
+
+    /// Position relative to the figure element, as fractions of its size with
+    /// y pointing up
+    fn fraction(&self, position: Point<Pixels>) -> Option<(f64, f64)> {
+        FigureArea::new(&self.element_bounds).map(|area| area.fraction(position))
+    }
+
+    /// Axes zoomed by the wheel at `position`: x only over the bottom margin,
+    /// y only over the left margin, both elsewhere
+    fn zoom_axes(&self, position: Point<Pixels>) -> (bool, bool) {
+        let Some(area) = FigureArea::new(&self.element_bounds) else {
+            return (true, true);
+        };
+        let (from_left, from_bottom) = area.edge_distance(position);
+        match (from_left < AXIS_MARGIN, from_bottom < AXIS_MARGIN) {
+            (true, false) => (false, true),
+            (false, true) => (true, false),
+            _ => (true, true),
+        }
+    }
+
+    fn on_scroll(
+        &mut self,
+        event: &ScrollWheelEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some((fx, fy)) = self.fraction(event.position) else {
+            return;
+        };
+        let delta = f32::from(event.delta.pixel_delta(px(16.0)).y) as f64;
+        let factor = (-delta * 0.005).exp();
+        let (zoom_x, zoom_y) = self.zoom_axes(event.position);
+
+        let mut viewport = self.viewport;
+        if zoom_x {
+            viewport.x = zoom(viewport.x, fx, factor);
+        }
+        if zoom_y {
+            viewport.y = zoom(viewport.y, fy, factor);
+        }
+        self.set_viewport(viewport, cx);
+    }
+
+    fn on_mouse_down(
+        &mut self,
+        event: &MouseDownEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if event.click_count == 2 {
+            self.drag = None;
+            self.set_viewport(self.home, cx);
+            return;
+        }
+        self.drag = Some(if event.modifiers.shift {
+            Drag::Box {
+                start: event.position,
+                end: event.position,
+            }
+        } else {
+            Drag::Pan {
+                last: event.position,
+            }
+        });
+    }
+
+    fn on_mouse_move(
+        &mut self,
+        event: &MouseMoveEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if event.pressed_button != Some(MouseButton::Left) {
+            return;
+        }
+        match self.drag {
+            Some(Drag::Pan { last }) => {
+                let (Some(from), Some(to)) = (self.fraction(last), self.fraction(event.position))
+                else {
+                    return;
+                };
+                let mut viewport = self.viewport;
+                viewport.x = pan(viewport.x, to.0 - from.0);
+                viewport.y = pan(viewport.y, to.1 - from.1);
+                self.drag = Some(Drag::Pan {
+                    last: event.position,
+                });
+                self.set_viewport(viewport, cx);
+            }
+            Some(Drag::Box { start, .. }) => {
+                self.drag = Some(Drag::Box {
+                    start,
+                    end: event.position,
+                });
+                cx.notify();
+            }
+            None => {}
+        }
+    }
+
+    /// Ends the drag, also when the button is released outside the figure
+    fn on_mouse_up(&mut self, _event: &MouseUpEvent, _window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(Drag::Box { start, end }) = self.drag.take() {
+            if let (Some(a), Some(b)) = (self.fraction(start), self.fraction(end)) {
+                let viewport = Viewport {
+                    x: select(self.viewport.x, a.0, b.0),
+                    y: select(self.viewport.y, a.1, b.1),
+                };
+                self.set_viewport(viewport, cx);
+            }
+        }
+        cx.notify();
+    }
+
+    /// Rectangle of the box zoom selection, relative to the figure element
+    fn render_selection(&self) -> Option<impl IntoElement> {
+        let Some(Drag::Box { start, end }) = self.drag else {
+            return None;
+        };
+        let area = FigureArea::new(&self.element_bounds)?;
+        let ((x0, y0), (x1, y1)) = (area.local(start), area.local(end));
+        Some(
+            div()
+                .absolute()
+                .left(px(x0.min(x1)))
+                .top(px(y0.min(y1)))
+                .w(px((x1 - x0).abs()))
+                .h(px((y1 - y0).abs()))
+                .border_1()
+                .border_color(Hsla::blue())
+                .bg(Hsla {
+                    a: 0.1,
+                    ..Hsla::blue()
+                }),
+        )
+    }
+}
+
+impl Render for PanZoomView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Record the bounds of the figure element to map mouse positions
+        let tracker = bounds_tracker(&self.element_bounds, cx.entity_id());
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(
+                div()
+                    .relative()
+                    .size_full()
+                    .on_scroll_wheel(cx.listener(Self::on_scroll))
+                    .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+                    .on_mouse_move(cx.listener(Self::on_mouse_move))
+                    .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
+                    .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
+                    .child(self.figure.clone())
+                    .child(tracker)
+                    .children(self.render_selection()),
+            )
+    }
+}
+
+/// A chirp, to have details worth zooming into
+fn chirp() -> Line {
+    let mut line = Line::new().color(Hsla::blue());
+    let steps = 4000;
+    for i in 0..=steps {
+        let x = 4.0 * PI * i as f64 / steps as f64;
+        line.add_point(point2(x, (x * x).sin()));
+    }
+    line
+}
+
+/// Visible data ranges of both axes
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Viewport {
+    x: (f64, f64),
+    y: (f64, f64),
+}
+
+impl Viewport {
+    fn bounds(&self) -> AxesBounds<f64, f64> {
+        AxesBounds::new(
+            AxisRange::new(self.x.0, self.x.1),
+            AxisRange::new(self.y.0, self.y.1),
+        )
+    }
+
+    /// Rejects degenerate ranges, e.g. after zooming in too far
+    fn is_valid(&self) -> bool {
+        let valid = |(min, max): (f64, f64)| {
+            min.is_finite() && max.is_finite() && max - min > f64::EPSILON * min.abs().max(1.0)
+        };
+        valid(self.x) && valid(self.y)
+    }
+}
+
+/// Mouse drag in progress
+#[derive(Clone, Copy, Debug)]
+enum Drag {
+    Pan {
+        last: Point<Pixels>,
+    },
+    Box {
+        start: Point<Pixels>,
+        end: Point<Pixels>,
+    },
+}
+
+/// Scales a range by `factor` around the point at `fraction` of the range
+fn zoom((min, max): (f64, f64), fraction: f64, factor: f64) -> (f64, f64) {
+    let center = min + (max - min) * fraction;
+    (
+        center - (center - min) * factor,
+        center + (max - center) * factor,
+    )
+}
+
+/// Moves a range so that the content follows a drag of `fraction` of the range
+fn pan((min, max): (f64, f64), fraction: f64) -> (f64, f64) {
+    let offset = (max - min) * fraction;
+    (min - offset, max - offset)
+}
+
+/// Sub-range between the fractions `a` and `b` of a range
+fn select((min, max): (f64, f64), a: f64, b: f64) -> (f64, f64) {
+    let (a, b) = (a.min(b).clamp(0.0, 1.0), a.max(b).clamp(0.0, 1.0));
+    (min + (max - min) * a, min + (max - min) * b)
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our interactive plot
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| PanZoomView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
@@ -0,0 +1,280 @@
+//! Retained Series Example
+//!
+//! This example demonstrates how to build a plot once and update it through
+//! stable handles, instead of clearing and regenerating every element on each
+//! frame. Series are added to a `SeriesStore` which returns a `SeriesId`, and
+//! can later be updated, hidden or removed through that id.
+//!
+//! The store is plotted a single time into the `AxesModel` and shared with the
+//! application. Every change marks the store dirty, and the view only asks for
+//! a new frame when it is dirty, so an idle plot does not use any CPU.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+use gpui::{
+    div, hsla, prelude::*, px, size, App, Application, Bounds, ClickEvent, Entity, Hsla, Window,
+    WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::{AxesContext, AxesModel};
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, GeometryAxes, Line};
+use parking_lot::RwLock;
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+/// Main application view containing the retained plot
+struct RetainedSeriesView {
+    figure: Entity<FigureView>,
+    store: SeriesStore,
+    ids: Vec<SeriesId>,
+}
+
+impl RetainedSeriesView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Retained Series - built once, updated by id".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        let x_range = AxisRange::new(0.0, 2.0 * PI);
+        let y_range = AxisRange::new(-1.5, 1.5);
+        let axes_bounds = AxesBounds::new(x_range, y_range);
+
+        let grid = GridModel::from_numbers(10, 8);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(axes_bounds, grid)));
+
+        // The store is plotted once, later changes go through the store
+        let store = SeriesStore::default();
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |axes| {
+                axes.plot(store.clone());
+            });
+        });
+        let ids = vec![store.add(Series::harmonic(1, Hsla::blue()))];
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        Self { figure, store, ids }
+    }
+
+    /// Requests a new frame only if the store changed
+    fn refresh(&mut self, cx: &mut Context<Self>) {
+        if self.store.take_dirty() {
+            cx.notify();
+        }
+    }
+
+    fn add(&mut self) {
+        let harmonic = self.ids.len() + 1;
+        let hue = (harmonic as f32 * 0.17) % 1.0;
+        let id = self
+            .store
+            .add(Series::harmonic(harmonic, hsla(hue, 0.8, 0.45, 1.0)));
+        self.ids.push(id);
+    }
+
+    fn update(&mut self) {
+        if let Some(&id) = self.ids.last() {
+            self.store.update(id, |series| series.scale(0.8));
+        }
+    }
+
+    fn toggle(&mut self) {
+        if let Some(&id) = self.ids.first() {
+            let visible = self.store.is_visible(id).unwrap_or(false);
+            self.store.set_visible(id, !visible);
+        }
+    }
+
+    fn remove(&mut self) {
+        if let Some(id) = self.ids.pop() {
+            self.store.remove(id);
+        }
+    }
+
+    fn render_button(
+        &self,
+        id: &'static str,
+        label: &'static str,
+        cx: &mut Context<Self>,
+        action: fn(&mut Self),
+    ) -> impl IntoElement {
+        div()
+            .id(id)
+            .px_2()
+            .border_1()
+            .border_color(Hsla::black())
+            .cursor_pointer()
+            .on_click(cx.listener(move |this, _: &ClickEvent, _window, cx| {
+                action(this);
+                this.refresh(cx);
+            }))
+            .child(label)
+    }
+}
+
+impl Render for RetainedSeriesView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Nothing is rebuilt here, the plot reads the store when painted
+        let buttons = [
+            self.render_button("add", "Add series", cx, Self::add),
+            self.render_button("update", "Scale last", cx, Self::update),
+            self.render_button("toggle", "Toggle first", cx, Self::toggle),
+            self.render_button("remove", "Remove last", cx, Self::remove),
+        ];
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(div().flex().flex_row().gap_2().p_2().children(buttons))
+            .child(self.figure.clone())
+    }
+}
+
+/// Stable handle to a series of a `SeriesStore`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct SeriesId(u64);
+
+/// A curve stored in a `SeriesStore`
+#[derive(Clone, Debug)]
+struct Series {
+    color: Hsla,
+    points: Vec<(f64, f64)>,
+}
+
+impl Series {
+    /// The `n`-th harmonic of a sine wave, with amplitude 1/n
+    fn harmonic(n: usize, color: Hsla) -> Self {
+        let steps = 400;
+        let points = (0..=steps)
+            .map(|i| {
+                let x = 2.0 * PI * i as f64 / steps as f64;
+                (x, (n as f64 * x).sin() / n as f64)
+            })
+            .collect();
+        Self { color, points }
+    }
+
+    fn scale(&mut self, factor: f64) {
+        for point in &mut self.points {
+            point.1 *= factor;
+        }
+    }
+}
+
+struct SeriesEntry {
+    id: SeriesId,
+    series: Series,
+    visible: bool,
+}
+
+#[derive(Default)]
+struct StoreState {
+    next_id: u64,
+    entries: Vec<SeriesEntry>,
+    dirty: bool,
+}
+
+/// Series shared between the application and the axes they are plotted in
+#[derive(Clone, Default)]
+struct SeriesStore {
+    state: Arc<RwLock<StoreState>>,
+}
+
+impl SeriesStore {
+    fn add(&self, series: Series) -> SeriesId {
+        let mut state = self.state.write();
+        let id = SeriesId(state.next_id);
+        state.next_id += 1;
+        state.entries.push(SeriesEntry {
+            id,
+            series,
+            visible: true,
+        });
+        state.dirty = true;
+        id
+    }
+
+    /// Applies `f` to the series, returns false if the id is unknown
+    fn update(&self, id: SeriesId, f: impl FnOnce(&mut Series)) -> bool {
+        let mut state = self.state.write();
+        let Some(entry) = state.entries.iter_mut().find(|entry| entry.id == id) else {
+            return false;
+        };
+        f(&mut entry.series);
+        state.dirty = true;
+        true
+    }
+
+    fn is_visible(&self, id: SeriesId) -> Option<bool> {
+        let state = self.state.read();
+        state
+            .entries
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| entry.visible)
+    }
+
+    /// Shows or hides the series, returns false if the id is unknown
+    fn set_visible(&self, id: SeriesId, visible: bool) -> bool {
+        let mut state = self.state.write();
+        let Some(entry) = state.entries.iter_mut().find(|entry| entry.id == id) else {
+            return false;
+        };
+        if entry.visible != visible {
+            entry.visible = visible;
+            state.dirty = true;
+        }
+        true
+    }
+
+    fn remove(&self, id: SeriesId) -> Option<Series> {
+        let mut state = self.state.write();
+        let index = state.entries.iter().position(|entry| entry.id == id)?;
+        state.dirty = true;
+        Some(state.entries.remove(index).series)
+    }
+
+    /// Returns whether the store changed since the last call
+    fn take_dirty(&self) -> bool {
+        std::mem::take(&mut self.state.write().dirty)
+    }
+}
+
+impl GeometryAxes for SeriesStore {
+    type X = f64;
+    type Y = f64;
+
+    fn render_axes(&mut self, cx: &mut AxesContext<Self::X, Self::Y>) {
+        let state = self.state.read();
+        for entry in state.entries.iter().filter(|entry| entry.visible) {
+            let mut line = Line::new().color(entry.series.color);
+            for &(x, y) in &entry.series.points {
+                line.add_point(point2(x, y));
+            }
+            line.render_axes(cx);
+        }
+    }
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our retained plot
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| RetainedSeriesView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
@@ -0,0 +1,333 @@
+//! Scatter Plot Example
+//!
+//! This example demonstrates how to draw unconnected measurement samples with
+//! per-point markers using gpui-plot. A `Scatter` draws each sample as a
+//! marker (circle, square, triangle, cross, plus or diamond), painted over the
+//! figure with `paint_layer` from the examples' common module.
+//!
+//! Marker sizes and outline widths are in pixels, so markers keep their shape
+//! and size whatever the axes ranges and the window size are. Closed markers
+//! are filled with a single path, and their outline is a ring of constant
+//! width around the shape; crosses and pluses are stroked. Per-point size and
+//! color overrides are used to encode a third variable.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{fill_path, paint_layer, stroke_path, Stroke};
+use gpui::{
+    div, hsla, point, prelude::*, px, size, App, Application, Bounds, Entity, Hsla, Pixels, Point,
+    Window, WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::AxesModel;
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{AxesBounds, AxisRange};
+use parking_lot::RwLock;
+use std::f64::consts::TAU;
+use std::sync::Arc;
+
+const X_MIN: f64 = 0.0;
+const X_MAX: f64 = 10.0;
+const Y_MIN: f64 = -1.0;
+const Y_MAX: f64 = 7.0;
+
+/// Main application view containing the scatter plot
+struct ScatterPlotView {
+    figure: Entity<FigureView>,
+    scatters: Vec<Scatter>,
+}
+
+impl ScatterPlotView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Scatter Plot - marker styles".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        // Set up axes bounds large enough for one row of samples per marker
+        let x_range = AxisRange::new(X_MIN, X_MAX);
+        let y_range = AxisRange::new(Y_MIN, Y_MAX);
+        let axes_bounds = AxesBounds::new(x_range, y_range);
+
+        let grid = GridModel::from_numbers(10, 8);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(axes_bounds, grid)));
+
+        // The axes only hold the grid, markers are painted over the figure
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |_axes| {});
+        });
+
+        // One row of noisy samples per marker shape
+        let markers = [
+            Marker::Circle,
+            Marker::Square,
+            Marker::Triangle,
+            Marker::Diamond,
+            Marker::Cross,
+            Marker::Plus,
+        ];
+        let mut scatters: Vec<Scatter> = markers
+            .into_iter()
+            .enumerate()
+            .map(|(row, marker)| {
+                let hue = row as f32 / markers.len() as f32;
+                let mut scatter = Scatter::new()
+                    .marker(marker)
+                    .size(12.0)
+                    .stroke(hsla(hue, 0.8, 0.35, 1.0));
+                if marker.is_closed() {
+                    scatter = scatter.fill(hsla(hue, 0.8, 0.65, 1.0));
+                }
+                for i in 0..20 {
+                    let x = 0.25 + i as f64 * 0.5;
+                    let y = row as f64 + 0.3 * jitter(row * 100 + i);
+                    scatter.add_point(x, y);
+                }
+                scatter
+            })
+            .collect();
+
+        // A third variable encoded with per-point size and color
+        let mut encoded = Scatter::new().marker(Marker::Circle).stroke(Hsla::black());
+        for i in 0..20 {
+            let x = 0.25 + i as f64 * 0.5;
+            let level = i as f64 / 19.0;
+            encoded.add_point_with(
+                x,
+                6.0,
+                6.0 + 18.0 * level as f32,
+                hsla(0.66 * (1.0 - level as f32), 0.9, 0.5, 1.0),
+            );
+        }
+        scatters.push(encoded);
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        Self { figure, scatters }
+    }
+}
+
+impl Render for ScatterPlotView {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        // Markers are painted over the figure, sized in pixels
+        let scatters = self.scatters.clone();
+        let markers = paint_layer((X_MIN, X_MAX), (Y_MIN, Y_MAX), move |window, to_pixels| {
+            for scatter in &scatters {
+                scatter.paint(window, to_pixels);
+            }
+        });
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(
+                div()
+                    .relative()
+                    .size_full()
+                    .child(self.figure.clone())
+                    .child(markers),
+            )
+    }
+}
+
+/// Marker shapes supported by `Scatter`
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Marker {
+    Circle,
+    Square,
+    Triangle,
+    Cross,
+    Plus,
+    Diamond,
+}
+
+impl Marker {
+    /// Returns true if the marker encloses an area that can be filled
+    fn is_closed(self) -> bool {
+        !matches!(self, Marker::Cross | Marker::Plus)
+    }
+
+    /// Shape of the marker in unit coordinates centered on the origin, y up:
+    /// a polygon for closed markers, and the strokes of the others
+    fn shape(self) -> Vec<Vec<(f64, f64)>> {
+        match self {
+            Marker::Circle => vec![(0..32)
+                .map(|i| {
+                    let angle = i as f64 / 32.0 * TAU;
+                    (angle.cos(), angle.sin())
+                })
+                .collect()],
+            Marker::Square => vec![vec![(-0.8, -0.8), (0.8, -0.8), (0.8, 0.8), (-0.8, 0.8)]],
+            Marker::Triangle => vec![vec![(0.0, 1.0), (-0.87, -0.5), (0.87, -0.5)]],
+            Marker::Diamond => vec![vec![(0.0, 1.0), (1.0, 0.0), (0.0, -1.0), (-1.0, 0.0)]],
+            Marker::Cross => vec![
+                vec![(-0.7, -0.7), (0.7, 0.7)],
+                vec![(-0.7, 0.7), (0.7, -0.7)],
+            ],
+            Marker::Plus => vec![vec![(-1.0, 0.0), (1.0, 0.0)], vec![(0.0, -1.0), (0.0, 1.0)]],
+        }
+    }
+
+    /// Distance from the center to the sides of a closed marker, in unit
+    /// coordinates. All closed shapes are regular around the origin, so
+    /// scaling them moves every side by the same distance
+    fn inradius(self) -> f64 {
+        match self {
+            Marker::Circle => 1.0,
+            Marker::Square => 0.8,
+            Marker::Triangle => 0.5,
+            Marker::Diamond => std::f64::consts::FRAC_1_SQRT_2,
+            Marker::Cross | Marker::Plus => 0.0,
+        }
+    }
+}
+
+/// A single scatter sample with optional size and color overrides
+#[derive(Clone, Debug)]
+struct ScatterPoint {
+    x: f64,
+    y: f64,
+    size: Option<f32>,
+    color: Option<Hsla>,
+}
+
+/// Unconnected samples drawn as markers
+#[derive(Clone)]
+struct Scatter {
+    points: Vec<ScatterPoint>,
+    marker: Marker,
+    size: f32,
+    width: f32,
+    stroke: Hsla,
+    fill: Option<Hsla>,
+}
+
+impl Scatter {
+    fn new() -> Self {
+        Self {
+            points: Vec::new(),
+            marker: Marker::Circle,
+            size: 8.0,
+            width: 1.0,
+            stroke: Hsla::blue(),
+            fill: None,
+        }
+    }
+
+    fn marker(mut self, marker: Marker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Default marker size in pixels, across the unit shape
+    fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    fn stroke(mut self, color: Hsla) -> Self {
+        self.stroke = color;
+        self
+    }
+
+    fn fill(mut self, color: Hsla) -> Self {
+        self.fill = Some(color);
+        self
+    }
+
+    fn add_point(&mut self, x: f64, y: f64) {
+        self.points.push(ScatterPoint {
+            x,
+            y,
+            size: None,
+            color: None,
+        });
+    }
+
+    /// Adds a sample overriding the marker size and color.
+    /// The color replaces the fill of closed markers and the stroke otherwise.
+    fn add_point_with(&mut self, x: f64, y: f64, size: f32, color: Hsla) {
+        self.points.push(ScatterPoint {
+            x,
+            y,
+            size: Some(size),
+            color: Some(color),
+        });
+    }
+
+    /// Paints the markers, `to_pixels` mapping data coordinates to window
+    /// pixels
+    fn paint(&self, window: &mut Window, to_pixels: &dyn Fn((f64, f64)) -> Point<Pixels>) {
+        let closed = self.marker.is_closed();
+        let shape = self.marker.shape();
+        for sample in &self.points {
+            let center = to_pixels((sample.x, sample.y));
+            if !f32::from(center.x).is_finite() || !f32::from(center.y).is_finite() {
+                continue;
+            }
+            let radius = sample.size.unwrap_or(self.size) / 2.0;
+            let (fill, stroke) = match (closed, self.fill) {
+                (true, Some(fill)) => (Some(sample.color.unwrap_or(fill)), self.stroke),
+                _ => (None, sample.color.unwrap_or(self.stroke)),
+            };
+            let place = |path: &[(f64, f64)], scale: f32| -> Vec<Point<Pixels>> {
+                path.iter()
+                    .map(|&(x, y)| {
+                        point(
+                            center.x + px(x as f32 * scale),
+                            center.y - px(y as f32 * scale),
+                        )
+                    })
+                    .collect()
+            };
+
+            if !closed {
+                let style = Stroke::new(Vec::new(), stroke).width(self.width);
+                for path in &shape {
+                    stroke_path(window, &place(path, radius), &style);
+                }
+                continue;
+            }
+            if let Some(fill) = fill {
+                fill_path(window, [place(&shape[0], radius)], fill);
+            }
+            // Outline as the ring between the shape grown and shrunk by half
+            // the width, painted as one even-odd path
+            let inradius = self.marker.inradius() as f32;
+            let half = self.width / 2.0 / inradius;
+            let rings = [
+                place(&shape[0], radius + half),
+                place(&shape[0], (radius - half).max(0.0)),
+            ];
+            fill_path(window, rings, stroke);
+        }
+    }
+}
+
+/// Deterministic pseudo-random value in [-0.5, 0.5)
+fn jitter(seed: usize) -> f64 {
+    ((seed as f64 * 12.9898).sin() * 43758.5453).fract().abs() - 0.5
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our scatter plot
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| ScatterPlotView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
@@ -20,8 +20,6 @@ use std::sync::Arc;
 
 /// Main application view containing the curve plot
 struct CurvePlotView {
-    model: Arc<RwLock<FigureModel>>,
-    axes_model: Arc<RwLock<AxesModel<f64, f64>>>,
     figure: Entity<FigureView>,
 }
 
@@ -40,36 +38,22 @@ impl CurvePlotView {
         let grid = GridModel::from_numbers(10, 8);
         let axes_model = Arc::new(RwLock::new(AxesModel::new(axes_bounds, grid)));
 
+        // Add the sine curve once, the figure keeps it between frames
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |axes| {
+                axes.plot(SineCurve::new());
+            });
+        });
+
         // Create the figure view
         let figure = cx.new(|_| FigureView::new(model.clone()));
 
-        Self {
-            model,
-            axes_model,
-            figure,
-        }
+        Self { figure }
     }
 }
 
 impl Render for CurvePlotView {
-    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        // Defer rendering to trigger updates
-        let id = cx.entity_id();
-        cx.defer(move |app| app.notify(id));
-
-        // Clear existing plots and create new curve
-        let mut model = self.model.write();
-        model.clear_plots();
-        model.add_plot_with(|plot| {
-            plot.add_axes_with(self.axes_model.clone(), |axes| {
-                axes.clear_elements();
-                
-                // Create the sine curve
-                let sine_curve = SineCurve::new();
-                axes.plot(sine_curve);
-            });
-        });
-
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
         // Return the main UI layout
         div()
             .size_full()
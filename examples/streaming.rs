@@ -0,0 +1,250 @@
+//! Streaming Example
+//!
+//! This example demonstrates how to plot live data. A `StreamingSeries` keeps
+//! the latest samples in a bounded ring buffer and can be fed with `push` and
+//! `extend` from any thread; here background threads append a noisy signal
+//! at 2 kHz and a slow reference signal at 10 Hz.
+//!
+//! The view shows a scrolling window over the last 10 seconds of data. The
+//! axes bounds follow the newest sample, and the plot is refreshed from a
+//! snapshot of the buffer by a task waking up `MAX_FPS` times per second,
+//! however fast the data arrives.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+use gpui::{
+    div, prelude::*, px, size, App, Application, Bounds, Entity, Hsla, Task, Window, WindowBounds,
+    WindowOptions,
+};
+use gpui_plot::figure::axes::{AxesContext, AxesModel};
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, GeometryAxes, Line};
+use parking_lot::RwLock;
+use std::collections::VecDeque;
+use std::f64::consts::TAU;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Width of the scrolling window, in seconds
+const WINDOW: f64 = 10.0;
+/// Number of samples kept by the ring buffer
+const CAPACITY: usize = 50_000;
+/// Maximum number of plot refreshes per second
+const MAX_FPS: f64 = 30.0;
+/// Rate of the simulated telemetry, in samples per second
+const SAMPLE_RATE: f64 = 2000.0;
+
+/// Main application view containing the live plot
+struct StreamingView {
+    axes_model: Arc<RwLock<AxesModel<f64, f64>>>,
+    figure: Entity<FigureView>,
+    series: Vec<StreamingSeries>,
+    /// Refreshes the plot until the view is dropped
+    _refresh: Task<()>,
+}
+
+impl StreamingView {
+    fn new(_window: &mut Window, cx: &mut Context<Self>) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Streaming - last 10 seconds".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        let grid = GridModel::from_numbers(10, 8);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(Self::bounds(0.0), grid)));
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |_| {});
+        });
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        let signal = StreamingSeries::new(CAPACITY, Hsla::blue());
+        let reference = StreamingSeries::new(CAPACITY, Hsla::red());
+        let start = Instant::now();
+        spawn_signal(signal.clone(), start);
+        spawn_reference(reference.clone(), start);
+
+        // The plot only changes at the capped refresh rate
+        let refresh = cx.spawn(async move |this, cx| loop {
+            let interval = Duration::from_secs_f64(1.0 / MAX_FPS);
+            cx.background_executor().timer(interval).await;
+            let refreshed = this.update(cx, |this, cx| {
+                this.refresh();
+                cx.notify();
+            });
+            // The view is gone
+            if refreshed.is_err() {
+                break;
+            }
+        });
+
+        Self {
+            axes_model,
+            figure,
+            series: vec![signal, reference],
+            _refresh: refresh,
+        }
+    }
+
+    /// Bounds of the window ending at `latest`, starting at 0 until it is full
+    fn bounds(latest: f64) -> AxesBounds<f64, f64> {
+        let end = latest.max(WINDOW);
+        AxesBounds::new(AxisRange::new(end - WINDOW, end), AxisRange::new(-1.5, 1.5))
+    }
+
+    /// Replaces the plot with snapshots of the visible part of the buffers
+    fn refresh(&mut self) {
+        let snapshots: Vec<Snapshot> = self
+            .series
+            .iter()
+            .map(|series| Snapshot {
+                points: series.snapshot(WINDOW),
+                color: series.color,
+            })
+            .collect();
+        let latest = snapshots
+            .iter()
+            .filter_map(|snapshot| snapshot.points.last())
+            .map(|point| point.0)
+            .fold(0.0, f64::max);
+
+        let mut axes = self.axes_model.write();
+        axes.bounds = Self::bounds(latest);
+        axes.clear_elements();
+        for snapshot in snapshots {
+            axes.plot(snapshot);
+        }
+    }
+}
+
+impl Render for StreamingView {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(self.figure.clone())
+    }
+}
+
+struct RingBuffer {
+    points: VecDeque<(f64, f64)>,
+    capacity: usize,
+}
+
+/// A series keeping its latest points in a bounded buffer, shared between
+/// the threads producing data and the view plotting it
+#[derive(Clone)]
+struct StreamingSeries {
+    buffer: Arc<RwLock<RingBuffer>>,
+    color: Hsla,
+}
+
+impl StreamingSeries {
+    fn new(capacity: usize, color: Hsla) -> Self {
+        Self {
+            buffer: Arc::new(RwLock::new(RingBuffer {
+                points: VecDeque::with_capacity(capacity),
+                capacity,
+            })),
+            color,
+        }
+    }
+
+    /// Appends a point, dropping the oldest one if the buffer is full
+    fn push(&self, x: f64, y: f64) {
+        self.extend([(x, y)]);
+    }
+
+    /// Appends points in order, dropping the oldest ones if the buffer is full
+    fn extend(&self, points: impl IntoIterator<Item = (f64, f64)>) {
+        let mut buffer = self.buffer.write();
+        for point in points {
+            if buffer.points.len() == buffer.capacity {
+                buffer.points.pop_front();
+            }
+            buffer.points.push_back(point);
+        }
+    }
+
+    /// Copy of the points within `window` of the newest x value
+    fn snapshot(&self, window: f64) -> Vec<(f64, f64)> {
+        let buffer = self.buffer.read();
+        let Some(&(latest, _)) = buffer.points.back() else {
+            return Vec::new();
+        };
+        // Points are ordered by x, so the visible ones are at the end
+        let start = buffer
+            .points
+            .partition_point(|point| point.0 < latest - window);
+        buffer.points.range(start..).copied().collect()
+    }
+}
+
+/// Points copied out of a `StreamingSeries` for one refresh
+#[derive(Clone)]
+struct Snapshot {
+    points: Vec<(f64, f64)>,
+    color: Hsla,
+}
+
+impl GeometryAxes for Snapshot {
+    type X = f64;
+    type Y = f64;
+
+    fn render_axes(&mut self, cx: &mut AxesContext<Self::X, Self::Y>) {
+        let mut line = Line::new().color(self.color);
+        for &(x, y) in &self.points {
+            line.add_point(point2(x, y));
+        }
+        line.render_axes(cx);
+    }
+}
+
+/// Simulates telemetry by appending samples of a noisy signal in batches
+fn spawn_signal(series: StreamingSeries, start: Instant) {
+    thread::spawn(move || {
+        let mut sent = 0u64;
+        loop {
+            let due = (start.elapsed().as_secs_f64() * SAMPLE_RATE) as u64;
+            series.extend((sent..due).map(|i| {
+                let t = i as f64 / SAMPLE_RATE;
+                let noise = ((i as f64 * 12.9898).sin() * 43758.5453).fract() * 0.2;
+                (t, (TAU * 0.3 * t).sin() * (TAU * 0.05 * t).cos() + noise)
+            }));
+            sent = due;
+            thread::sleep(Duration::from_millis(5));
+        }
+    });
+}
+
+/// Appends one sample of a slow reference signal at a time
+fn spawn_reference(series: StreamingSeries, start: Instant) {
+    thread::spawn(move || loop {
+        let t = start.elapsed().as_secs_f64();
+        series.push(t, 0.8 * (TAU * 0.05 * t).cos());
+        thread::sleep(Duration::from_millis(100));
+    });
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our live plot
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| StreamingView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
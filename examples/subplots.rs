@@ -0,0 +1,396 @@
+//! Subplots Example
+//!
+//! This example demonstrates how to lay out several plots in a grid. A
+//! `GridSpec` divides the window into rows and columns with relative size
+//! ratios and spacing, and each `Subplot` occupies the cells given by its
+//! `SubplotSpec`, possibly spanning several rows or columns. Every subplot
+//! has its own `FigureModel`, `AxesModel` and `FigureView`, placed with gpui
+//! elements at the position computed by the grid.
+//!
+//! Subplots can share their x or y axis with others, like matplotlib's
+//! `subplots(sharex=True, sharey=True)`: zooming one of them with the mouse
+//! wheel applies the same range to every subplot of the group. Here the two
+//! time plots share x, and the velocity plot shares y with the phase portrait,
+//! whose y axis is the velocity. Double-click to reset all subplots.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{bounds_tracker, ElementBounds, FigureArea};
+use gpui::{
+    div, prelude::*, px, relative, size, App, Application, Bounds, Entity, Hsla, MouseButton,
+    MouseDownEvent, ScrollWheelEvent, Window, WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::AxesModel;
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, Line};
+use parking_lot::RwLock;
+use std::f64::consts::TAU;
+use std::sync::Arc;
+
+/// Main application view containing the grid of subplots
+struct SubplotsView {
+    grid: GridSpec,
+    subplots: Vec<Subplot>,
+}
+
+impl SubplotsView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Two rows and two columns, the first row and column being larger
+        let grid = GridSpec::new(2, 2)
+            .row_ratios(vec![2.0, 1.0])
+            .column_ratios(vec![2.0, 1.0])
+            .spacing(0.02);
+
+        let time: Vec<f64> = (0..=1000).map(|i| i as f64 / 100.0).collect();
+        let signal = |t: f64| (TAU * 0.5 * t).sin() * (-0.15 * t).exp();
+        let envelope = |t: f64| (-0.15 * t).exp();
+        let velocity = |t: f64| (TAU * 0.5 * t).cos() * (-0.15 * t).exp();
+
+        let subplots = vec![
+            // Spans both columns of the first row
+            Subplot::new(
+                cx,
+                "Signal",
+                SubplotSpec::new(0, 0).span(1, 2),
+                Viewport {
+                    x: (0.0, 10.0),
+                    y: (-1.2, 1.2),
+                },
+            )
+            .series(time.iter().map(|&t| (t, signal(t))).collect(), Hsla::blue())
+            .series(
+                time.iter().map(|&t| (t, envelope(t))).collect(),
+                Hsla::red(),
+            )
+            .share_x(0),
+            Subplot::new(
+                cx,
+                "Velocity",
+                SubplotSpec::new(1, 0),
+                Viewport {
+                    x: (0.0, 10.0),
+                    y: (-1.2, 1.2),
+                },
+            )
+            .series(
+                time.iter().map(|&t| (t, velocity(t))).collect(),
+                Hsla::green(),
+            )
+            .share_x(0)
+            .share_y(0),
+            // Phase portrait, with the velocity on y
+            Subplot::new(
+                cx,
+                "Phase",
+                SubplotSpec::new(1, 1),
+                Viewport {
+                    x: (-1.2, 1.2),
+                    y: (-1.2, 1.2),
+                },
+            )
+            .series(
+                time.iter().map(|&t| (signal(t), velocity(t))).collect(),
+                Hsla::black(),
+            )
+            .share_y(0),
+        ];
+
+        Self { grid, subplots }
+    }
+
+    /// Zooms subplot `index` around the cursor, and the subplots sharing an
+    /// axis with it
+    fn on_scroll(&mut self, index: usize, event: &ScrollWheelEvent, cx: &mut Context<Self>) {
+        let subplot = &self.subplots[index];
+        let Some((fx, fy)) = subplot.fraction(event) else {
+            return;
+        };
+        let delta = f32::from(event.delta.pixel_delta(px(16.0)).y) as f64;
+        let factor = (-delta * 0.005).exp();
+        let viewport = Viewport {
+            x: zoom(subplot.viewport.x, fx, factor),
+            y: zoom(subplot.viewport.y, fy, factor),
+        };
+        if !viewport.is_valid() {
+            return;
+        }
+
+        let (share_x, share_y) = (subplot.share_x, subplot.share_y);
+        for (i, other) in self.subplots.iter_mut().enumerate() {
+            let mut updated = other.viewport;
+            if i == index || (share_x.is_some() && other.share_x == share_x) {
+                updated.x = viewport.x;
+            }
+            if i == index || (share_y.is_some() && other.share_y == share_y) {
+                updated.y = viewport.y;
+            }
+            other.set_viewport(updated);
+        }
+        cx.notify();
+    }
+
+    fn on_mouse_down(
+        &mut self,
+        event: &MouseDownEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if event.click_count == 2 {
+            for subplot in &mut self.subplots {
+                subplot.set_viewport(subplot.home);
+            }
+            cx.notify();
+        }
+    }
+}
+
+impl Render for SubplotsView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let view = cx.entity_id();
+        let cells = self.subplots.iter().enumerate().map(|(index, subplot)| {
+            // Record the bounds of the subplot to map mouse positions
+            let tracker = bounds_tracker(&subplot.element_bounds, view);
+
+            let (left, top, width, height) = self.grid.rect(subplot.spec);
+            div()
+                .absolute()
+                .left(relative(left))
+                .top(relative(top))
+                .w(relative(width))
+                .h(relative(height))
+                .on_scroll_wheel(
+                    cx.listener(move |this, event: &ScrollWheelEvent, _window, cx| {
+                        this.on_scroll(index, event, cx)
+                    }),
+                )
+                .child(subplot.figure.clone())
+                .child(tracker)
+        });
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .relative()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+            .children(cells)
+    }
+}
+
+/// Division of the window into rows and columns
+#[derive(Clone, Debug)]
+struct GridSpec {
+    row_ratios: Vec<f32>,
+    column_ratios: Vec<f32>,
+    /// Space between cells, as a fraction of the window size
+    spacing: f32,
+}
+
+impl GridSpec {
+    /// Grid of equally sized rows and columns
+    fn new(rows: usize, columns: usize) -> Self {
+        Self {
+            row_ratios: vec![1.0; rows.max(1)],
+            column_ratios: vec![1.0; columns.max(1)],
+            spacing: 0.0,
+        }
+    }
+
+    /// Relative heights of the rows
+    fn row_ratios(mut self, ratios: Vec<f32>) -> Self {
+        if !ratios.is_empty() {
+            self.row_ratios = ratios;
+        }
+        self
+    }
+
+    /// Relative widths of the columns
+    fn column_ratios(mut self, ratios: Vec<f32>) -> Self {
+        if !ratios.is_empty() {
+            self.column_ratios = ratios;
+        }
+        self
+    }
+
+    fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing.max(0.0);
+        self
+    }
+
+    /// Start and length of the cells `start..start + span` along one
+    /// direction, as fractions of the window
+    fn extent(&self, ratios: &[f32], start: usize, span: usize) -> (f32, f32) {
+        let count = ratios.len();
+        let start = start.min(count - 1);
+        let end = (start + span.max(1)).min(count);
+        // The spacing takes at most half of the window, so that cells never
+        // get a negative size
+        let spacing = self.spacing.min(0.5 / (count + 1) as f32);
+        let available = 1.0 - spacing * (count + 1) as f32;
+        let total: f32 = ratios.iter().sum();
+        let size = |ratio: f32| available * ratio / total;
+
+        let offset =
+            spacing * (start + 1) as f32 + ratios[..start].iter().copied().map(size).sum::<f32>();
+        let length = spacing * (end - start - 1) as f32
+            + ratios[start..end].iter().copied().map(size).sum::<f32>();
+        (offset, length)
+    }
+
+    /// Left, top, width and height of a subplot, as fractions of the window
+    fn rect(&self, spec: SubplotSpec) -> (f32, f32, f32, f32) {
+        let (left, width) = self.extent(&self.column_ratios, spec.column, spec.column_span);
+        let (top, height) = self.extent(&self.row_ratios, spec.row, spec.row_span);
+        (left, top, width, height)
+    }
+}
+
+/// Cells of a `GridSpec` occupied by a subplot
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct SubplotSpec {
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+}
+
+impl SubplotSpec {
+    fn new(row: usize, column: usize) -> Self {
+        Self {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+        }
+    }
+
+    fn span(mut self, rows: usize, columns: usize) -> Self {
+        self.row_span = rows;
+        self.column_span = columns;
+        self
+    }
+}
+
+/// Visible data ranges of both axes
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Viewport {
+    x: (f64, f64),
+    y: (f64, f64),
+}
+
+impl Viewport {
+    fn bounds(&self) -> AxesBounds<f64, f64> {
+        AxesBounds::new(
+            AxisRange::new(self.x.0, self.x.1),
+            AxisRange::new(self.y.0, self.y.1),
+        )
+    }
+
+    /// Rejects degenerate ranges, e.g. after zooming in too far
+    fn is_valid(&self) -> bool {
+        let valid = |(min, max): (f64, f64)| {
+            min.is_finite() && max.is_finite() && max - min > f64::EPSILON * min.abs().max(1.0)
+        };
+        valid(self.x) && valid(self.y)
+    }
+}
+
+/// A figure placed in a grid cell, optionally sharing its axes
+struct Subplot {
+    axes_model: Arc<RwLock<AxesModel<f64, f64>>>,
+    figure: Entity<FigureView>,
+    spec: SubplotSpec,
+    home: Viewport,
+    viewport: Viewport,
+    /// Groups of subplots whose x or y ranges follow each other
+    share_x: Option<usize>,
+    share_y: Option<usize>,
+    /// Bounds of the figure element, updated on every paint
+    element_bounds: ElementBounds,
+}
+
+impl Subplot {
+    fn new(cx: &mut App, title: &str, spec: SubplotSpec, home: Viewport) -> Self {
+        let model = Arc::new(RwLock::new(FigureModel::new(title.to_string())));
+        let grid = GridModel::from_numbers(10, 8);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(home.bounds(), grid)));
+
+        // The axes are added once, zooming only writes their bounds
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |_axes| {});
+        });
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+        Self {
+            axes_model,
+            figure,
+            spec,
+            home,
+            viewport: home,
+            share_x: None,
+            share_y: None,
+            element_bounds: ElementBounds::default(),
+        }
+    }
+
+    /// Plots a curve into the axes of the subplot
+    fn series(self, points: Vec<(f64, f64)>, color: Hsla) -> Self {
+        let mut line = Line::new().color(color);
+        for (x, y) in points {
+            line.add_point(point2(x, y));
+        }
+        self.axes_model.write().plot(line);
+        self
+    }
+
+    fn share_x(mut self, group: usize) -> Self {
+        self.share_x = Some(group);
+        self
+    }
+
+    fn share_y(mut self, group: usize) -> Self {
+        self.share_y = Some(group);
+        self
+    }
+
+    /// Writes the viewport back to the axes model of the subplot
+    fn set_viewport(&mut self, viewport: Viewport) {
+        self.viewport = viewport;
+        self.axes_model.write().bounds = viewport.bounds();
+    }
+
+    /// Cursor position as fractions of the subplot, with y pointing up
+    fn fraction(&self, event: &ScrollWheelEvent) -> Option<(f64, f64)> {
+        FigureArea::new(&self.element_bounds).map(|area| area.fraction(event.position))
+    }
+}
+
+/// Scales a range by `factor` around the point at `fraction` of the range
+fn zoom((min, max): (f64, f64), fraction: f64, factor: f64) -> (f64, f64) {
+    let center = min + (max - min) * fraction;
+    (
+        center - (center - min) * factor,
+        center + (max - center) * factor,
+    )
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(900.0), px(700.0)), cx);
+
+        // Open the main window with our subplots
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| SubplotsView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
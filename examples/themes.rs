@@ -0,0 +1,372 @@
+//! Themes Example
+//!
+//! This example demonstrates how to style a figure with a `PlotTheme` instead
+//! of hardcoded colors. A theme sets the background, the color of the axes
+//! frame, text and gridlines, the font, the default line width and a palette
+//! of series colors. Series that don't specify a color take the next color of
+//! the palette, cycling when there are more series than colors, while series
+//! with an explicit color keep it.
+//!
+//! Light, dark and high-contrast themes are built in. The "System" choice
+//! follows the appearance of the host window, so the plot switches between
+//! the light and dark themes together with the operating system. Click the
+//! theme button to cycle through the choices.
+//!
+//! The figure is placed in an element with the background, text color and
+//! font of the theme, which the `FigureView` inherits for its title and shows
+//! behind the axes, with the grid of the `GridModel` reduced to its frame.
+//! Gridlines, frame and series are `Stroke`s from the examples' common module
+//! painted over the figure in the colors of the theme, the series with the
+//! line width of the theme in pixels. The legend and the theme button use the
+//! font of the theme.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{stroke_layer, LineCap, Stroke};
+use gpui::{
+    div, hsla, prelude::*, px, rgb, size, App, Application, Bounds, ClickEvent, Entity, Hsla,
+    Subscription, Window, WindowAppearance, WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::AxesModel;
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{AxesBounds, AxisRange};
+use parking_lot::RwLock;
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+const X_RANGE: (f64, f64) = (0.0, 2.0 * PI);
+const Y_RANGE: (f64, f64) = (-1.5, 1.5);
+
+/// Main application view containing the themed plot
+struct ThemesView {
+    figure: Entity<FigureView>,
+    series: Vec<Series>,
+    choice: ThemeChoice,
+    /// Renders again when the appearance of the window changes
+    _appearance: Subscription,
+}
+
+impl ThemesView {
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Themes - light, dark, high contrast".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        // Gridlines use the theme colors and are painted by `themed_grid` instead
+        let x_range = AxisRange::new(X_RANGE.0, X_RANGE.1);
+        let y_range = AxisRange::new(Y_RANGE.0, Y_RANGE.1);
+        let axes_bounds = AxesBounds::new(x_range, y_range);
+        let grid = GridModel::from_numbers(1, 1);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(axes_bounds, grid)));
+
+        // The axes only hold the frame, strokes are painted over the figure
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |_axes| {});
+        });
+
+        // Phase shifted sines taking their colors from the palette, one more
+        // than the palette has colors so that the last one wraps around, and
+        // a reference curve with an explicit color
+        let mut series: Vec<Series> = (0..7)
+            .map(|i| {
+                let phase = i as f64 * PI / 7.0;
+                Series::new(&format!("phase {i}π/7"), move |x| (x + phase).sin())
+            })
+            .collect();
+        series.push(
+            Series::new("reference", |x| 1.2 * (0.5 * x).cos()).color(hsla(0.0, 0.0, 0.5, 1.0)),
+        );
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        // The "System" theme follows the window, so render again when the
+        // operating system switches between light and dark
+        let appearance = cx.observe_window_appearance(window, |_this, _window, cx| cx.notify());
+
+        Self {
+            figure,
+            series,
+            choice: ThemeChoice::System,
+            _appearance: appearance,
+        }
+    }
+
+    /// Color of each series: explicit colors are kept, the other series cycle
+    /// through the palette
+    fn colors(&self, theme: &PlotTheme) -> Vec<Hsla> {
+        let mut next = 0;
+        self.series
+            .iter()
+            .map(|series| {
+                series.color.unwrap_or_else(|| {
+                    let color = theme.palette[next % theme.palette.len()];
+                    next += 1;
+                    color
+                })
+            })
+            .collect()
+    }
+
+    fn render_legend(&self, colors: &[Hsla]) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_row()
+            .gap_3()
+            .children(self.series.iter().zip(colors).map(|(series, color)| {
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_1()
+                    .child(div().w(px(16.0)).h(px(3.0)).bg(*color))
+                    .child(series.label.clone())
+            }))
+    }
+}
+
+impl Render for ThemesView {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Resolved on every render to follow the window appearance
+        let theme = self.choice.resolve(window.appearance());
+        let colors = self.colors(&theme);
+        let mut strokes = themed_grid(&theme, 10, 8);
+        for (series, color) in self.series.iter().zip(&colors) {
+            strokes.push(Stroke::new(series.points(), *color).width(theme.line_width));
+        }
+
+        let button = div()
+            .id("theme")
+            .px_2()
+            .border_1()
+            .border_color(theme.axes)
+            .cursor_pointer()
+            .on_click(cx.listener(|this, _: &ClickEvent, _window, cx| {
+                this.choice = this.choice.next();
+                cx.notify();
+            }))
+            .child(format!("Theme: {} ({})", self.choice.name(), theme.name));
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(theme.background)
+            .text_color(theme.foreground)
+            .font_family(theme.font_family)
+            .text_size(px(theme.font_size))
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    .p_2()
+                    .child(button)
+                    .child(self.render_legend(&colors)),
+            )
+            .child(
+                div()
+                    .relative()
+                    .size_full()
+                    .child(self.figure.clone())
+                    .child(stroke_layer(strokes, X_RANGE, Y_RANGE)),
+            )
+    }
+}
+
+/// Colors, font and line width of a figure
+#[derive(Clone, Debug, PartialEq)]
+struct PlotTheme {
+    name: &'static str,
+    background: Hsla,
+    /// Color of the text around the figure
+    foreground: Hsla,
+    /// Color of the frame of the axes
+    axes: Hsla,
+    grid: Hsla,
+    font_family: &'static str,
+    /// Font size in pixels
+    font_size: f32,
+    /// Default stroke width in pixels
+    line_width: f32,
+    /// Colors given in turn to the series without an explicit color
+    palette: Vec<Hsla>,
+}
+
+impl PlotTheme {
+    fn light() -> Self {
+        Self {
+            name: "Light",
+            background: gpui::white(),
+            foreground: gpui::black(),
+            axes: hsla(0.0, 0.0, 0.2, 1.0),
+            grid: hsla(0.0, 0.0, 0.88, 1.0),
+            font_family: "Helvetica",
+            font_size: 14.0,
+            line_width: 1.5,
+            palette: palette(&[0x1f77b4, 0xff7f0e, 0x2ca02c, 0xd62728, 0x9467bd, 0x8c564b]),
+        }
+    }
+
+    fn dark() -> Self {
+        Self {
+            name: "Dark",
+            background: hsla(0.0, 0.0, 0.12, 1.0),
+            foreground: hsla(0.0, 0.0, 0.9, 1.0),
+            axes: hsla(0.0, 0.0, 0.7, 1.0),
+            grid: hsla(0.0, 0.0, 0.25, 1.0),
+            palette: palette(&[0x4e9fe5, 0xffa94d, 0x5cd65c, 0xff6b6b, 0xc49cff, 0xd9a38b]),
+            ..Self::light()
+        }
+    }
+
+    /// Black background, thick lines and saturated colors far apart in hue
+    fn high_contrast() -> Self {
+        Self {
+            name: "High contrast",
+            background: gpui::black(),
+            foreground: gpui::white(),
+            axes: gpui::white(),
+            grid: hsla(0.0, 0.0, 0.45, 1.0),
+            font_size: 16.0,
+            line_width: 3.0,
+            palette: palette(&[0xffff00, 0x00ffff, 0xff00ff, 0x00ff00, 0xff8000, 0xffffff]),
+            ..Self::light()
+        }
+    }
+
+    /// Light or dark theme matching the appearance of the host window
+    fn for_appearance(appearance: WindowAppearance) -> Self {
+        match appearance {
+            WindowAppearance::Light | WindowAppearance::VibrantLight => Self::light(),
+            WindowAppearance::Dark | WindowAppearance::VibrantDark => Self::dark(),
+        }
+    }
+}
+
+/// Converts hexadecimal RGB colors to a palette
+fn palette(colors: &[u32]) -> Vec<Hsla> {
+    colors.iter().map(|color| rgb(*color).into()).collect()
+}
+
+/// Theme selected with the theme button
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ThemeChoice {
+    System,
+    Light,
+    Dark,
+    HighContrast,
+}
+
+impl ThemeChoice {
+    fn next(self) -> Self {
+        match self {
+            Self::System => Self::Light,
+            Self::Light => Self::Dark,
+            Self::Dark => Self::HighContrast,
+            Self::HighContrast => Self::System,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::System => "System",
+            Self::Light => "Light",
+            Self::Dark => "Dark",
+            Self::HighContrast => "High contrast",
+        }
+    }
+
+    fn resolve(self, appearance: WindowAppearance) -> PlotTheme {
+        match self {
+            Self::System => PlotTheme::for_appearance(appearance),
+            Self::Light => PlotTheme::light(),
+            Self::Dark => PlotTheme::dark(),
+            Self::HighContrast => PlotTheme::high_contrast(),
+        }
+    }
+}
+
+/// A function sampled over the x range, with an optional fixed color
+#[derive(Clone)]
+struct Series {
+    label: String,
+    function: Arc<dyn Fn(f64) -> f64 + Send + Sync>,
+    color: Option<Hsla>,
+}
+
+impl Series {
+    fn new(label: &str, function: impl Fn(f64) -> f64 + Send + Sync + 'static) -> Self {
+        Self {
+            label: label.to_string(),
+            function: Arc::new(function),
+            color: None,
+        }
+    }
+
+    fn color(mut self, color: Hsla) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    fn points(&self) -> Vec<(f64, f64)> {
+        let steps = 300;
+        (0..=steps)
+            .map(|i| {
+                let x = X_RANGE.0 + (X_RANGE.1 - X_RANGE.0) * i as f64 / steps as f64;
+                (x, (self.function)(x))
+            })
+            .collect()
+    }
+}
+
+/// Gridlines and frame of the axes in the colors of a theme, with
+/// `x_divisions` columns and `y_divisions` rows
+fn themed_grid(theme: &PlotTheme, x_divisions: usize, y_divisions: usize) -> Vec<Stroke> {
+    let (left, right) = X_RANGE;
+    let (bottom, top) = Y_RANGE;
+    let (columns, rows) = (x_divisions.max(1), y_divisions.max(1));
+
+    let mut strokes = Vec::new();
+    for i in 1..columns {
+        let x = left + (right - left) * i as f64 / columns as f64;
+        strokes.push(Stroke::new(vec![(x, bottom), (x, top)], theme.grid));
+    }
+    for i in 1..rows {
+        let y = bottom + (top - bottom) * i as f64 / rows as f64;
+        strokes.push(Stroke::new(vec![(left, y), (right, y)], theme.grid));
+    }
+
+    // Square caps close the corner where the frame starts and ends
+    let frame = vec![
+        (left, bottom),
+        (right, bottom),
+        (right, top),
+        (left, top),
+        (left, bottom),
+    ];
+    strokes.push(Stroke::new(frame, theme.axes).cap(LineCap::Square));
+    strokes
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(900.0), px(600.0)), cx);
+
+        // Open the main window with our themed plot
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| ThemesView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
@@ -0,0 +1,574 @@
+//! Time Axis Example
+//!
+//! This example demonstrates how to plot time series against human readable
+//! timestamps with chrono. The axes keep x as seconds since the Unix epoch,
+//! and a `TimeAxis` converts `DateTime` and `NaiveDate` values to and from
+//! that scale.
+//!
+//! Ticks are calendar aware: depending on the visible span, a `TimeStep` of
+//! seconds, minutes, hours, days, months or years is picked, and ticks are
+//! aligned on round values of the calendar in the selected time zone, e.g.
+//! local midnight, the first day of a quarter or the start of a year. Labels
+//! are formatted for the step, and show the date whenever it changes. In the
+//! local time zone the offset is looked up for each tick, so ticks stay on
+//! round local times across daylight saving changes, as on 10 March 2024 in
+//! the US or 31 March 2024 in Europe.
+//!
+//! The series and deploy markers are plotted once. Zooming writes the bounds
+//! of the `AxesModel`, and the ticks of the plotted `TimeGrid` are shared
+//! with the view, which updates them when the visible span, the width of the
+//! figure or the time zone changes.
+//!
+//! Use the mouse wheel to zoom along x around the cursor, double-click to
+//! reset, and click the time zone button to switch between zones.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use chrono::{
+    DateTime, Datelike, FixedOffset, Local, Months, NaiveDate, NaiveDateTime, TimeDelta, TimeZone,
+    Timelike, Utc,
+};
+use common::{bounds_tracker, fraction, ElementBounds, FigureArea};
+use gpui::{
+    div, hsla, prelude::*, px, size, App, Application, Bounds, ClickEvent, Entity, Hsla,
+    MouseButton, MouseDownEvent, ScrollWheelEvent, Window, WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::{AxesContext, AxesModel};
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, GeometryAxes, Line};
+use parking_lot::RwLock;
+use std::f64::consts::TAU;
+use std::fmt;
+use std::sync::Arc;
+
+const Y_MIN: f64 = 0.0;
+const Y_MAX: f64 = 100.0;
+
+/// Minimum distance between two tick labels, in pixels
+const LABEL_SPACING: f32 = 110.0;
+
+/// Main application view containing the time series
+struct TimeAxisView {
+    axes_model: Arc<RwLock<AxesModel<f64, f64>>>,
+    figure: Entity<FigureView>,
+    /// Ticks drawn by the plotted `TimeGrid`
+    ticks: Arc<RwLock<Vec<f64>>>,
+    home: (f64, f64),
+    axis: TimeAxis,
+    zones: Vec<(&'static str, Zone)>,
+    zone: usize,
+    /// Bounds of the figure element, updated on every paint
+    element_bounds: ElementBounds,
+}
+
+impl TimeAxisView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Time Axis - CPU load over two weeks".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        // One sample every five minutes, from the first of March 2024
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let load: Vec<(f64, f64)> = (0..14 * 24 * 12)
+            .map(|i| {
+                let time = start + TimeDelta::minutes(5 * i);
+                let day = (time.hour() as f64 + time.minute() as f64 / 60.0) / 24.0;
+                let weekend = time.weekday().number_from_monday() > 5;
+                let noise = ((i as f64 * 12.9898).sin() * 43758.5453).fract();
+                let base = if weekend { 25.0 } else { 45.0 };
+                (
+                    TimeAxis::seconds(time),
+                    base - 20.0 * (TAU * day).cos() + 5.0 * noise,
+                )
+            })
+            .collect();
+        let deploys = vec![
+            NaiveDate::from_ymd_opt(2024, 3, 5).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 12).unwrap(),
+        ];
+        let home = (
+            TimeAxis::seconds(start),
+            TimeAxis::seconds(start + TimeDelta::days(14)),
+        );
+
+        let zones = vec![
+            ("UTC", Zone::Fixed(FixedOffset::east_opt(0).unwrap())),
+            ("Local", Zone::Local),
+            (
+                "India",
+                Zone::Fixed(FixedOffset::east_opt(5 * 3600 + 1800).unwrap()),
+            ),
+        ];
+        let axis = TimeAxis::new(home, zones[0].1);
+
+        let grid = GridModel::from_numbers(1, 10);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(axis.bounds(), grid)));
+
+        // Plot once, the ticks of the grid are updated on render
+        let ticks = Arc::new(RwLock::new(Vec::new()));
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |axes| {
+                axes.plot(TimeGrid::new(ticks.clone()));
+
+                let mut line = Line::new().color(Hsla::blue());
+                for &(x, y) in &load {
+                    line.add_point(point2(x, y));
+                }
+                axes.plot(line);
+
+                // Deploys are whole days, drawn at midnight UTC
+                for &date in &deploys {
+                    let x = TimeAxis::date_seconds(date);
+                    let mut marker = Line::new().color(Hsla::red());
+                    marker.add_point(point2(x, Y_MIN));
+                    marker.add_point(point2(x, Y_MAX));
+                    axes.plot(marker);
+                }
+            });
+        });
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        Self {
+            axes_model,
+            figure,
+            ticks,
+            home,
+            axis,
+            zones,
+            zone: 0,
+            element_bounds: ElementBounds::default(),
+        }
+    }
+
+    fn set_visible(&mut self, visible: (f64, f64), cx: &mut Context<Self>) {
+        // At least a minute, at most a thousand years
+        let span = visible.1 - visible.0;
+        if !(60.0..=1000.0 * TimeUnit::Year.seconds()).contains(&span) {
+            return;
+        }
+        self.axis.min = visible.0;
+        self.axis.max = visible.1;
+        self.axes_model.write().bounds = self.axis.bounds();
+        cx.notify();
+    }
+
+    /// Largest number of labels fitting in the figure
+    fn max_ticks(&self) -> usize {
+        FigureArea::new(&self.element_bounds)
+            .map(|area| (area.width() / LABEL_SPACING) as usize)
+            .unwrap_or(8)
+            .max(2)
+    }
+
+    fn on_scroll(
+        &mut self,
+        event: &ScrollWheelEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(area) = FigureArea::new(&self.element_bounds) else {
+            return;
+        };
+        let (fraction, _) = area.fraction(event.position);
+        let delta = f32::from(event.delta.pixel_delta(px(16.0)).y) as f64;
+        let factor = (-delta * 0.005).exp();
+
+        let (min, max) = (self.axis.min, self.axis.max);
+        let center = min + (max - min) * fraction;
+        let visible = (
+            center - (center - min) * factor,
+            center + (max - center) * factor,
+        );
+        self.set_visible(visible, cx);
+    }
+
+    fn on_mouse_down(
+        &mut self,
+        event: &MouseDownEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if event.click_count == 2 {
+            self.set_visible(self.home, cx);
+        }
+    }
+
+    /// Tick labels along the bottom of the figure
+    fn render_labels(&self, labels: Vec<(f64, String)>) -> Option<impl IntoElement> {
+        let area = FigureArea::new(&self.element_bounds)?;
+        let range = (self.axis.min, self.axis.max);
+        let labels = labels.into_iter().map(|(x, label)| {
+            let (left, _) = area.offset((fraction(range, x), 0.0));
+            div()
+                .absolute()
+                .left(px(left + 4.0))
+                .bottom(px(4.0))
+                .text_sm()
+                .child(label)
+        });
+        Some(div().absolute().size_full().children(labels))
+    }
+}
+
+impl Render for TimeAxisView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // The ticks depend on the visible span, the width and the zone
+        let step = self.axis.step(self.max_ticks());
+        let labels = self.axis.labels(step);
+        *self.ticks.write() = labels.iter().map(|(x, _)| *x).collect();
+
+        let (name, zone) = self.zones[self.zone];
+        let zone = div()
+            .id("zone")
+            .px_2()
+            .border_1()
+            .border_color(Hsla::black())
+            .cursor_pointer()
+            .on_click(cx.listener(|this, _: &ClickEvent, _window, cx| {
+                this.zone = (this.zone + 1) % this.zones.len();
+                this.axis.zone = this.zones[this.zone].1;
+                cx.notify();
+            }))
+            .child(format!("Time zone: {name} ({zone})"));
+
+        // Record the bounds of the figure element to map mouse positions
+        let tracker = bounds_tracker(&self.element_bounds, cx.entity_id());
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex_col()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_2()
+                    .p_2()
+                    .child(zone)
+                    .child(format!("Ticks every {step}")),
+            )
+            .child(
+                div()
+                    .relative()
+                    .size_full()
+                    .on_scroll_wheel(cx.listener(Self::on_scroll))
+                    .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+                    .child(self.figure.clone())
+                    .child(tracker)
+                    .children(self.render_labels(labels)),
+            )
+    }
+}
+
+/// Calendar unit of a tick step
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+impl TimeUnit {
+    /// Average length in seconds, used to pick a step
+    fn seconds(self) -> f64 {
+        match self {
+            TimeUnit::Second => 1.0,
+            TimeUnit::Minute => 60.0,
+            TimeUnit::Hour => 3600.0,
+            TimeUnit::Day => 86_400.0,
+            TimeUnit::Month => 2_629_746.0,
+            TimeUnit::Year => 31_556_952.0,
+        }
+    }
+}
+
+/// Distance between two ticks, as a number of calendar units
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TimeStep {
+    unit: TimeUnit,
+    count: u32,
+}
+
+impl fmt::Display for TimeStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unit = format!("{:?}", self.unit).to_lowercase();
+        match self.count {
+            1 => write!(f, "{unit}"),
+            count => write!(f, "{count} {unit}s"),
+        }
+    }
+}
+
+/// Steps from the finest to the coarsest
+const STEPS: &[TimeStep] = &[
+    TimeStep::new(TimeUnit::Second, 1),
+    TimeStep::new(TimeUnit::Second, 5),
+    TimeStep::new(TimeUnit::Second, 15),
+    TimeStep::new(TimeUnit::Second, 30),
+    TimeStep::new(TimeUnit::Minute, 1),
+    TimeStep::new(TimeUnit::Minute, 5),
+    TimeStep::new(TimeUnit::Minute, 15),
+    TimeStep::new(TimeUnit::Minute, 30),
+    TimeStep::new(TimeUnit::Hour, 1),
+    TimeStep::new(TimeUnit::Hour, 3),
+    TimeStep::new(TimeUnit::Hour, 6),
+    TimeStep::new(TimeUnit::Hour, 12),
+    TimeStep::new(TimeUnit::Day, 1),
+    TimeStep::new(TimeUnit::Day, 2),
+    TimeStep::new(TimeUnit::Day, 7),
+    TimeStep::new(TimeUnit::Month, 1),
+    TimeStep::new(TimeUnit::Month, 3),
+    TimeStep::new(TimeUnit::Month, 6),
+    TimeStep::new(TimeUnit::Year, 1),
+    TimeStep::new(TimeUnit::Year, 2),
+    TimeStep::new(TimeUnit::Year, 5),
+    TimeStep::new(TimeUnit::Year, 10),
+    TimeStep::new(TimeUnit::Year, 50),
+    TimeStep::new(TimeUnit::Year, 100),
+];
+
+impl TimeStep {
+    const fn new(unit: TimeUnit, count: u32) -> Self {
+        Self { unit, count }
+    }
+
+    fn seconds(&self) -> f64 {
+        self.unit.seconds() * self.count as f64
+    }
+
+    /// Rounds `time` down to a multiple of the step within the next larger
+    /// unit, weeks starting on Monday
+    fn floor(&self, time: NaiveDateTime) -> Option<NaiveDateTime> {
+        let count = self.count;
+        let date = time.date();
+        match self.unit {
+            TimeUnit::Second => {
+                date.and_hms_opt(time.hour(), time.minute(), time.second() / count * count)
+            }
+            TimeUnit::Minute => date.and_hms_opt(time.hour(), time.minute() / count * count, 0),
+            TimeUnit::Hour => date.and_hms_opt(time.hour() / count * count, 0, 0),
+            TimeUnit::Day => {
+                let days = date.num_days_from_ce();
+                let aligned = days - (days - 1).rem_euclid(count as i32);
+                NaiveDate::from_num_days_from_ce_opt(aligned)?.and_hms_opt(0, 0, 0)
+            }
+            TimeUnit::Month => {
+                let month = time.month0() / count * count + 1;
+                NaiveDate::from_ymd_opt(time.year(), month, 1)?.and_hms_opt(0, 0, 0)
+            }
+            TimeUnit::Year => {
+                let year = time.year().div_euclid(count as i32) * count as i32;
+                NaiveDate::from_ymd_opt(year, 1, 1)?.and_hms_opt(0, 0, 0)
+            }
+        }
+    }
+
+    fn next(&self, time: NaiveDateTime) -> Option<NaiveDateTime> {
+        let count = self.count as i64;
+        match self.unit {
+            TimeUnit::Second => time.checked_add_signed(TimeDelta::seconds(count)),
+            TimeUnit::Minute => time.checked_add_signed(TimeDelta::minutes(count)),
+            TimeUnit::Hour => time.checked_add_signed(TimeDelta::hours(count)),
+            TimeUnit::Day => time.checked_add_signed(TimeDelta::days(count)),
+            TimeUnit::Month => time.checked_add_months(Months::new(self.count)),
+            TimeUnit::Year => time.checked_add_months(Months::new(12 * self.count)),
+        }
+    }
+
+    /// Format of the labels, with the date for steps shorter than a day
+    fn format(&self, with_date: bool) -> &'static str {
+        match (self.unit, with_date) {
+            (TimeUnit::Second, false) => "%H:%M:%S",
+            (TimeUnit::Second, true) => "%b %d %H:%M:%S",
+            (TimeUnit::Minute | TimeUnit::Hour, false) => "%H:%M",
+            (TimeUnit::Minute | TimeUnit::Hour, true) => "%b %d %H:%M",
+            (TimeUnit::Day, _) => "%a %b %d",
+            (TimeUnit::Month, _) => "%b %Y",
+            (TimeUnit::Year, _) => "%Y",
+        }
+    }
+}
+
+/// Time zone ticks are aligned and labeled in
+#[derive(Clone, Copy, Debug)]
+enum Zone {
+    /// The same offset all year round
+    Fixed(FixedOffset),
+    /// The system time zone, whose offset changes with daylight saving time
+    Local,
+}
+
+impl Zone {
+    /// `time` in this zone, with the offset in effect at that instant
+    fn convert(&self, time: DateTime<Utc>) -> DateTime<FixedOffset> {
+        match self {
+            Zone::Fixed(offset) => time.with_timezone(offset),
+            Zone::Local => Local.from_utc_datetime(&time.naive_utc()).fixed_offset(),
+        }
+    }
+
+    /// The instant of the wall clock time `time`, the earliest one if the
+    /// clock goes back, or `None` if it is skipped when the clock goes forward
+    fn resolve(&self, time: &NaiveDateTime) -> Option<DateTime<FixedOffset>> {
+        match self {
+            Zone::Fixed(offset) => offset.from_local_datetime(time).earliest(),
+            Zone::Local => Local
+                .from_local_datetime(time)
+                .earliest()
+                .map(|time| time.fixed_offset()),
+        }
+    }
+}
+
+impl fmt::Display for Zone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Zone::Fixed(offset) => write!(f, "{offset}"),
+            Zone::Local => write!(f, "with daylight saving"),
+        }
+    }
+}
+
+/// Visible time range, in seconds since the Unix epoch, and the time zone
+/// ticks are aligned and labeled in
+#[derive(Clone, Copy, Debug)]
+struct TimeAxis {
+    min: f64,
+    max: f64,
+    zone: Zone,
+}
+
+impl TimeAxis {
+    fn new((min, max): (f64, f64), zone: Zone) -> Self {
+        Self { min, max, zone }
+    }
+
+    fn seconds<Tz: TimeZone>(time: DateTime<Tz>) -> f64 {
+        time.timestamp() as f64 + time.timestamp_subsec_nanos() as f64 * 1e-9
+    }
+
+    /// Midnight UTC of `date`
+    fn date_seconds(date: NaiveDate) -> f64 {
+        Self::seconds(date.and_time(Default::default()).and_utc())
+    }
+
+    fn datetime(&self, seconds: f64) -> Option<DateTime<FixedOffset>> {
+        let whole = seconds.floor();
+        let nanos = ((seconds - whole) * 1e9) as u32;
+        DateTime::from_timestamp(whole as i64, nanos).map(|time| self.zone.convert(time))
+    }
+
+    fn bounds(&self) -> AxesBounds<f64, f64> {
+        AxesBounds::new(
+            AxisRange::new(self.min, self.max),
+            AxisRange::new(Y_MIN, Y_MAX),
+        )
+    }
+
+    /// Finest step giving at most `max_ticks` ticks
+    fn step(&self, max_ticks: usize) -> TimeStep {
+        let span = self.max - self.min;
+        STEPS
+            .iter()
+            .copied()
+            .find(|step| span / step.seconds() <= max_ticks as f64)
+            .unwrap_or(STEPS[STEPS.len() - 1])
+    }
+
+    /// Visible ticks of `step`, aligned in the local calendar
+    fn ticks(&self, step: TimeStep) -> Vec<DateTime<FixedOffset>> {
+        let Some(start) = self.datetime(self.min) else {
+            return Vec::new();
+        };
+        let mut ticks = Vec::new();
+        let mut local = step.floor(start.naive_local());
+        while let Some(naive) = local {
+            // Wall clock times skipped by a daylight saving change get no tick
+            if let Some(time) = self.zone.resolve(&naive) {
+                let seconds = Self::seconds(time);
+                if seconds > self.max {
+                    break;
+                }
+                if seconds >= self.min {
+                    ticks.push(time);
+                }
+            }
+            local = step.next(naive);
+        }
+        ticks
+    }
+
+    /// Positions and labels of the ticks, the date being repeated when it
+    /// changes
+    fn labels(&self, step: TimeStep) -> Vec<(f64, String)> {
+        let mut previous = None;
+        self.ticks(step)
+            .into_iter()
+            .map(|time| {
+                let with_date = previous != Some(time.date_naive());
+                previous = Some(time.date_naive());
+                (
+                    Self::seconds(time),
+                    time.format(step.format(with_date)).to_string(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Vertical grid lines at the ticks of a `TimeAxis`, shared with the view
+/// that updates them
+#[derive(Clone)]
+struct TimeGrid {
+    ticks: Arc<RwLock<Vec<f64>>>,
+}
+
+impl TimeGrid {
+    fn new(ticks: Arc<RwLock<Vec<f64>>>) -> Self {
+        Self { ticks }
+    }
+}
+
+impl GeometryAxes for TimeGrid {
+    type X = f64;
+    type Y = f64;
+
+    fn render_axes(&mut self, cx: &mut AxesContext<Self::X, Self::Y>) {
+        let ticks = self.ticks.read().clone();
+        for tick in ticks {
+            let mut line = Line::new().color(hsla(0.0, 0.0, 0.75, 1.0));
+            line.add_point(point2(tick, Y_MIN));
+            line.add_point(point2(tick, Y_MAX));
+            line.render_axes(cx);
+        }
+    }
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
+
+        // Open the main window with our time series
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| TimeAxisView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}
@@ -0,0 +1,512 @@
+//! Twin Axes Example
+//!
+//! This example demonstrates how to plot two quantities with different units
+//! over the same axes: the magnitude (dB) and phase (degrees) of a second
+//! order low-pass filter over a logarithmic frequency axis. The phase is bound
+//! to a secondary y axis on the right, and a secondary x axis on top shows the
+//! period matching each frequency.
+//!
+//! `TwinAxes` holds the primary and optional secondary axes, each with its own
+//! range, scale and ticks. Every `BoundLine` chooses which x and y axis it is
+//! bound to, and its points are mapped to the position they have on that axis,
+//! expressed in the coordinates of the primary axes, which are the ones of the
+//! `AxesModel`.
+//!
+//! Gridlines follow the primary axes and tick marks are drawn on all four
+//! sides. Tick labels are laid out with gpui elements around the figure, the
+//! y labels starting below the title row of the figure, see `TITLE_HEIGHT`.
+//!
+//! The example is designed to compile on Linux, macOS, and Windows.
+
+mod common;
+
+use common::{multiples, nice_step, Scale, TITLE_HEIGHT};
+use gpui::{
+    div, hsla, prelude::*, px, relative, size, App, Application, Bounds, Entity, Hsla, Window,
+    WindowBounds, WindowOptions,
+};
+use gpui_plot::figure::axes::{AxesContext, AxesModel};
+use gpui_plot::figure::figure::{FigureModel, FigureView};
+use gpui_plot::figure::grid::GridModel;
+use gpui_plot::geometry::{point2, AxesBounds, AxisRange, GeometryAxes, Line};
+use parking_lot::RwLock;
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+/// Main application view containing the filter response
+struct TwinAxesView {
+    figure: Entity<FigureView>,
+    twin: Arc<TwinAxes>,
+}
+
+impl TwinAxesView {
+    fn new(_window: &mut Window, cx: &mut App) -> Self {
+        // Create the main figure model
+        let model = FigureModel::new("Twin Axes - low-pass response".to_string());
+        let model = Arc::new(RwLock::new(model));
+
+        // Frequency and magnitude are the primary axes, phase and period the
+        // secondary ones. The period decreases with the frequency, so its
+        // range is reversed.
+        let twin = TwinAxes::new(
+            Axis::new("Frequency (Hz)", 20.0, 20000.0).scale(Scale::Log { base: 10.0 }),
+            Axis::new("Magnitude (dB)", -60.0, 12.0).step(12.0),
+        )
+        .secondary_x(Axis::new("Period (ms)", 50.0, 0.05).scale(Scale::Log { base: 10.0 }))
+        .secondary_y(Axis::new("Phase (°)", -180.0, 0.0).step(45.0));
+        let twin = Arc::new(twin);
+
+        // The axes model is expressed in the scaled primary coordinates
+        let (x_min, x_max) = twin.x.range();
+        let (y_min, y_max) = twin.y.range();
+        let axes_bounds =
+            AxesBounds::new(AxisRange::new(x_min, x_max), AxisRange::new(y_min, y_max));
+
+        // Gridlines follow the scales and are drawn by `TwinGrid` instead
+        let grid = GridModel::from_numbers(1, 1);
+        let axes_model = Arc::new(RwLock::new(AxesModel::new(axes_bounds, grid)));
+
+        // Second order low-pass filter with a 1 kHz cutoff and a resonance,
+        // sampled evenly along the log frequency axis
+        let cutoff = 1000.0;
+        let q = 2.0;
+        let mut magnitude = BoundLine::new(twin.clone(), Hsla::blue());
+        let mut phase =
+            BoundLine::new(twin.clone(), Hsla::red()).bind(AxisSide::Primary, AxisSide::Secondary);
+        let steps = 400;
+        for i in 0..=steps {
+            let f = 20.0 * 1000f64.powf(i as f64 / steps as f64);
+            let ratio = f / cutoff;
+            let real = 1.0 - ratio * ratio;
+            let imaginary = ratio / q;
+            magnitude.add_point(f, -10.0 * (real * real + imaginary * imaginary).log10());
+            phase.add_point(f, -imaginary.atan2(real) * 180.0 / PI);
+        }
+
+        // Add the decorations and both curves once, the figure keeps them
+        model.write().add_plot_with(|plot| {
+            plot.add_axes_with(axes_model.clone(), |axes| {
+                axes.plot(TwinGrid::new(twin.clone()));
+                axes.plot(magnitude);
+                axes.plot(phase);
+            });
+        });
+
+        // Create the figure view
+        let figure = cx.new(|_| FigureView::new(model.clone()));
+
+        Self { figure, twin }
+    }
+
+    /// Tick labels of an x axis, positioned proportionally along the figure
+    fn render_x_labels(axis: &Axis) -> impl IntoElement {
+        div()
+            .relative()
+            .h(px(20.0))
+            .children(axis.ticks().into_iter().map(|tick| {
+                div()
+                    .absolute()
+                    .left(relative(axis.fraction(tick) as f32))
+                    .ml(px(-30.0))
+                    .w(px(60.0))
+                    .flex()
+                    .justify_center()
+                    .text_sm()
+                    .child(format_value(tick))
+            }))
+    }
+
+    /// Tick labels of a y axis, positioned proportionally along the figure
+    /// and aligned toward it
+    fn render_y_labels(axis: &Axis, side: AxisSide, color: Hsla) -> impl IntoElement {
+        div()
+            .relative()
+            .w(px(48.0))
+            .flex_1()
+            .text_color(color)
+            .children(axis.ticks().into_iter().map(move |tick| {
+                let label = div()
+                    .absolute()
+                    .top(relative(1.0 - axis.fraction(tick) as f32))
+                    .mt(px(-10.0))
+                    .text_sm()
+                    .child(format_value(tick));
+                match side {
+                    AxisSide::Primary => label.right(px(4.0)),
+                    AxisSide::Secondary => label.left(px(4.0)),
+                }
+            }))
+    }
+
+    /// Title and tick labels of a y axis, with room for the x labels and the
+    /// title of the figure
+    fn render_y_axis(axis: &Axis, side: AxisSide, color: Hsla) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_col()
+            .child(
+                div()
+                    .h(px(40.0 + TITLE_HEIGHT))
+                    .flex()
+                    .justify_center()
+                    .text_sm()
+                    .text_color(color)
+                    .child(axis.title.clone()),
+            )
+            .child(Self::render_y_labels(axis, side, color))
+            .child(div().h(px(40.0)))
+    }
+}
+
+impl Render for TwinAxesView {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let twin = &self.twin;
+        let secondary_x = twin.x2.as_ref().map(|axis| {
+            div()
+                .flex()
+                .flex_col()
+                .child(
+                    div()
+                        .h(px(20.0))
+                        .flex()
+                        .justify_center()
+                        .child(axis.title.clone()),
+                )
+                .child(Self::render_x_labels(axis))
+        });
+        let secondary_y = twin
+            .y2
+            .as_ref()
+            .map(|axis| Self::render_y_axis(axis, AxisSide::Secondary, Hsla::red()));
+
+        // Return the main UI layout
+        div()
+            .size_full()
+            .flex()
+            .flex_row()
+            .bg(gpui::white())
+            .text_color(gpui::black())
+            .child(Self::render_y_axis(
+                &twin.y,
+                AxisSide::Primary,
+                Hsla::blue(),
+            ))
+            .child(
+                div()
+                    .flex_1()
+                    .flex()
+                    .flex_col()
+                    .children(secondary_x)
+                    .child(div().flex_1().child(self.figure.clone()))
+                    .child(Self::render_x_labels(&twin.x))
+                    .child(
+                        div()
+                            .h(px(20.0))
+                            .flex()
+                            .justify_center()
+                            .child(twin.x.title.clone()),
+                    ),
+            )
+            .children(secondary_y)
+    }
+}
+
+/// Range, scale and ticks of one axis
+#[derive(Clone, Debug)]
+struct Axis {
+    title: String,
+    min: f64,
+    max: f64,
+    scale: Scale,
+    /// Distance between ticks of a linear axis, chosen automatically if unset
+    step: Option<f64>,
+}
+
+impl Axis {
+    fn new(title: &str, min: f64, max: f64) -> Self {
+        Self {
+            title: title.to_string(),
+            min,
+            max,
+            scale: Scale::Linear,
+            step: None,
+        }
+    }
+
+    fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    fn step(mut self, step: f64) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Maps a value to scaled coordinates
+    fn forward(&self, value: f64) -> f64 {
+        self.scale.forward(value)
+    }
+
+    /// Range of the axis in scaled coordinates
+    fn range(&self) -> (f64, f64) {
+        (self.forward(self.min), self.forward(self.max))
+    }
+
+    /// Position of a value along the axis, from 0 at `min` to 1 at `max`
+    fn fraction(&self, value: f64) -> f64 {
+        let (start, end) = self.range();
+        (self.forward(value) - start) / (end - start)
+    }
+
+    /// Value at a position along the axis, in scaled coordinates
+    fn position(&self, fraction: f64) -> f64 {
+        let (start, end) = self.range();
+        start + (end - start) * fraction
+    }
+
+    fn contains(&self, value: f64) -> bool {
+        let epsilon = (self.max - self.min).abs() * 1e-9;
+        value >= self.min.min(self.max) - epsilon && value <= self.max.max(self.min) + epsilon
+    }
+
+    /// Tick values, at 1, 2 and 5 times the powers of ten of a decimal log
+    /// axis and at the powers of the base of other log axes
+    fn ticks(&self) -> Vec<f64> {
+        let (low, high) = (self.min.min(self.max), self.max.max(self.min));
+        match self.scale {
+            Scale::Linear => {
+                let step = self.step.unwrap_or_else(|| nice_step(low, high, 6));
+                multiples(low, high, step)
+            }
+            Scale::Log { base } => {
+                let factors: &[f64] = if base == 10.0 {
+                    &[1.0, 2.0, 5.0]
+                } else {
+                    &[1.0]
+                };
+                let first = low.log(base).floor() as i32;
+                let last = high.log(base).ceil() as i32;
+                (first..=last)
+                    .flat_map(|power| factors.iter().map(move |factor| factor * base.powi(power)))
+                    .filter(|tick| self.contains(*tick))
+                    .collect()
+            }
+            // Evenly spaced in scaled coordinates
+            Scale::SymLog { .. } | Scale::Custom { .. } => {
+                let (start, end) = self.range();
+                let (start, end) = (start.min(end), end.max(start));
+                multiples(start, end, nice_step(start, end, 6))
+                    .into_iter()
+                    .map(|position| self.scale.inverse(position))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Axis a plotted element is bound to, in each direction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AxisSide {
+    /// Bottom x axis or left y axis
+    Primary,
+    /// Top x axis or right y axis
+    Secondary,
+}
+
+/// Primary axes of the `AxesModel`, with optional secondary axes sharing the
+/// same area
+#[derive(Clone, Debug)]
+struct TwinAxes {
+    x: Axis,
+    y: Axis,
+    x2: Option<Axis>,
+    y2: Option<Axis>,
+}
+
+impl TwinAxes {
+    fn new(x: Axis, y: Axis) -> Self {
+        Self {
+            x,
+            y,
+            x2: None,
+            y2: None,
+        }
+    }
+
+    fn secondary_x(mut self, axis: Axis) -> Self {
+        self.x2 = Some(axis);
+        self
+    }
+
+    fn secondary_y(mut self, axis: Axis) -> Self {
+        self.y2 = Some(axis);
+        self
+    }
+
+    /// Axis in a direction, falling back to the primary one when there is no
+    /// secondary axis
+    fn x_axis(&self, side: AxisSide) -> &Axis {
+        match side {
+            AxisSide::Secondary => self.x2.as_ref().unwrap_or(&self.x),
+            AxisSide::Primary => &self.x,
+        }
+    }
+
+    fn y_axis(&self, side: AxisSide) -> &Axis {
+        match side {
+            AxisSide::Secondary => self.y2.as_ref().unwrap_or(&self.y),
+            AxisSide::Primary => &self.y,
+        }
+    }
+
+    /// Maps a point given on the chosen axes to the primary coordinates
+    fn map(&self, x_side: AxisSide, y_side: AxisSide, (x, y): (f64, f64)) -> (f64, f64) {
+        (
+            self.x.position(self.x_axis(x_side).fraction(x)),
+            self.y.position(self.y_axis(y_side).fraction(y)),
+        )
+    }
+}
+
+/// Line bound to a primary or secondary axis in each direction
+#[derive(Clone)]
+struct BoundLine {
+    twin: Arc<TwinAxes>,
+    points: Vec<(f64, f64)>,
+    color: Hsla,
+    x_side: AxisSide,
+    y_side: AxisSide,
+}
+
+impl BoundLine {
+    fn new(twin: Arc<TwinAxes>, color: Hsla) -> Self {
+        Self {
+            twin,
+            points: Vec::new(),
+            color,
+            x_side: AxisSide::Primary,
+            y_side: AxisSide::Primary,
+        }
+    }
+
+    fn bind(mut self, x_side: AxisSide, y_side: AxisSide) -> Self {
+        self.x_side = x_side;
+        self.y_side = y_side;
+        self
+    }
+
+    /// Adds a point, in the units of the axes the line is bound to
+    fn add_point(&mut self, x: f64, y: f64) {
+        self.points.push((x, y));
+    }
+}
+
+impl GeometryAxes for BoundLine {
+    type X = f64;
+    type Y = f64;
+
+    fn render_axes(&mut self, cx: &mut AxesContext<Self::X, Self::Y>) {
+        let mut line = Line::new().color(self.color);
+        for &point in &self.points {
+            let (x, y) = self.twin.map(self.x_side, self.y_side, point);
+            line.add_point(point2(x, y));
+        }
+        line.render_axes(cx);
+    }
+}
+
+/// Gridlines of the primary axes, and tick marks on all four sides
+#[derive(Clone)]
+struct TwinGrid {
+    twin: Arc<TwinAxes>,
+}
+
+impl TwinGrid {
+    fn new(twin: Arc<TwinAxes>) -> Self {
+        Self { twin }
+    }
+}
+
+impl GeometryAxes for TwinGrid {
+    type X = f64;
+    type Y = f64;
+
+    fn render_axes(&mut self, cx: &mut AxesContext<Self::X, Self::Y>) {
+        let twin = &self.twin;
+        let (left, right) = twin.x.range();
+        let (bottom, top) = twin.y.range();
+        let color = hsla(0.0, 0.0, 0.85, 1.0);
+        // Tick lengths as a fraction of the other axis span
+        let x_length = (top - bottom) * 0.02;
+        let y_length = (right - left) * 0.015;
+
+        for tick in twin.x.ticks() {
+            let x = twin.x.forward(tick);
+            draw_segment(cx, color, (x, bottom), (x, top));
+        }
+        for tick in twin.y.ticks() {
+            let y = twin.y.forward(tick);
+            draw_segment(cx, color, (left, y), (right, y));
+        }
+
+        for side in [AxisSide::Primary, AxisSide::Secondary] {
+            let (edge, length) = match side {
+                AxisSide::Primary => (bottom, x_length),
+                AxisSide::Secondary => (top, -x_length),
+            };
+            let axis = twin.x_axis(side);
+            for tick in axis.ticks() {
+                let x = twin.x.position(axis.fraction(tick));
+                draw_segment(cx, Hsla::black(), (x, edge), (x, edge + length));
+            }
+
+            let (edge, length) = match side {
+                AxisSide::Primary => (left, y_length),
+                AxisSide::Secondary => (right, -y_length),
+            };
+            let axis = twin.y_axis(side);
+            for tick in axis.ticks() {
+                let y = twin.y.position(axis.fraction(tick));
+                draw_segment(cx, Hsla::black(), (edge, y), (edge + length, y));
+            }
+        }
+    }
+}
+
+/// Shortest label of a tick, using a "k" suffix for thousands
+fn format_value(value: f64) -> String {
+    if value.abs() >= 1000.0 {
+        format!("{}k", value / 1000.0)
+    } else {
+        // Round away the noise of the powers of ten, e.g. 0.05 + 1e-18
+        let rounded = (value * 1e6).round() / 1e6;
+        format!("{rounded}")
+    }
+}
+
+/// Draws a straight segment in axes coordinates
+fn draw_segment(cx: &mut AxesContext<f64, f64>, color: Hsla, from: (f64, f64), to: (f64, f64)) {
+    let mut line = Line::new().color(color);
+    line.add_point(point2(from.0, from.1));
+    line.add_point(point2(to.0, to.1));
+    line.render_axes(cx);
+}
+
+fn main() {
+    // Initialize the GPUI application
+    Application::new().run(|cx: &mut App| {
+        // Create a centered window
+        let bounds = Bounds::centered(None, size(px(900.0), px(600.0)), cx);
+
+        // Open the main window with our twin axes plot
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |window, cx| cx.new(|cx| TwinAxesView::new(window, cx)),
+        )
+        .unwrap();
+    });
+}